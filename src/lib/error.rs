@@ -0,0 +1,167 @@
+use crate::utils::graph::traversal::CycleError;
+use crate::utils::{ascii, grid, matching};
+use std::char::ParseCharError;
+use std::fmt::{Debug, Display};
+use std::num::ParseIntError;
+use std::path::Path;
+
+/// Result type for fallible puzzle parsing and solving
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors raised while parsing puzzle input or solving a puzzle. Errors from
+/// the utilities convert into it, so that days can use `?` throughout.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// A line of input that could not be parsed, numbered from one
+    #[error("line {line}: {message}: {text:?}")]
+    Parse {
+        line: usize,
+        text: String,
+        message: String,
+    },
+
+    /// Malformed input described by a static message, as most `FromStr`
+    /// implementations in this crate do.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+
+    #[error("failed to parse value: {0}")]
+    ParseInt(#[from] ParseIntError),
+
+    #[error("failed to parse value: {0}")]
+    ParseChar(#[from] ParseCharError),
+
+    /// Error indicating a call to [`str::lines`] has yielded nothing. Often
+    /// used in [Advent of Code](https://adventofcode.com) problems when the
+    /// first line of an input indicates something about the other lines (such
+    /// as their length).
+    ///
+    /// # Examples
+    ///
+    /// `s.lines().next()` returns `None` if `s == ""`:
+    /// ```
+    /// use advent::Error::EmptyInputStr;
+    ///
+    /// assert_eq!("".lines().next().ok_or(EmptyInputStr), Err(EmptyInputStr));
+    /// assert_eq!("a".lines().next().ok_or(EmptyInputStr), Ok("a"));
+    /// ```
+    #[error("expected string to contain at least one line")]
+    EmptyInputStr,
+
+    #[error("attempted to use iterator after exhausting it")]
+    WrongSizeIterator,
+
+    #[error(transparent)]
+    Grid(#[from] grid::error::Error),
+
+    #[error(transparent)]
+    Ascii(#[from] ascii::error::Error),
+
+    /// A graph without the structure a puzzle relies on, such as a cycle in
+    /// what should be a dependency order
+    #[error("graph error: {0}")]
+    Graph(String),
+
+    /// Valid input for which the puzzle has no answer
+    #[error("no solution: {0}")]
+    Solver(String),
+}
+
+impl Error {
+    /// Describes why `text`, found on the `line`th line of the input counting
+    /// from one, could not be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent::Error;
+    ///
+    /// let error = Error::at_line(3, "jmp +x", "invalid digit");
+    /// assert_eq!(error.to_string(), "line 3: invalid digit: \"jmp +x\"");
+    /// ```
+    pub fn at_line<M: Display>(line: usize, text: &str, message: M) -> Self {
+        Self::Parse {
+            line,
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Formats the error as a `file:line: message` diagnostic for the input
+    /// read from `file`, leaving out the line for errors without one
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent::Error;
+    /// use std::path::Path;
+    ///
+    /// let error = Error::at_line(3, "jmp +x", "invalid digit");
+    /// assert_eq!(
+    ///     error.diagnostic(Path::new("day23.txt")),
+    ///     "day23.txt:3: invalid digit: \"jmp +x\""
+    /// );
+    /// ```
+    pub fn diagnostic(&self, file: &Path) -> String {
+        match self {
+            Self::Parse {
+                line,
+                text,
+                message,
+            } => format!("{}:{}: {}: {:?}", file.display(), line, message, text),
+            _ => format!("{}: {}", file.display(), self),
+        }
+    }
+}
+
+impl From<&'static str> for Error {
+    fn from(value: &'static str) -> Self {
+        Self::InvalidInput(value)
+    }
+}
+
+impl<T: Debug> From<CycleError<T>> for Error {
+    fn from(value: CycleError<T>) -> Self {
+        Self::Graph(format!("{} ({:?})", value, value.0))
+    }
+}
+
+impl<K: Debug> From<matching::Error<K>> for Error {
+    fn from(value: matching::Error<K>) -> Self {
+        Self::Solver(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_pair(s: &str) -> Result<(i32, char)> {
+        let (number, letter) = s.split_once(' ').ok_or("expected a space")?;
+        Ok((number.parse()?, letter.parse()?))
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(parse_pair("12 x"), Ok((12, 'x')));
+        assert_eq!(
+            parse_pair("12"),
+            Err(Error::InvalidInput("expected a space"))
+        );
+        assert!(matches!(parse_pair("x x"), Err(Error::ParseInt(_))));
+        assert!(matches!(parse_pair("1 xy"), Err(Error::ParseChar(_))));
+
+        let cycle = Error::from(CycleError(vec!['a', 'b']));
+        assert_eq!(
+            cycle.to_string(),
+            "graph error: graph contains a cycle; 2 nodes could not be ordered (['a', 'b'])"
+        );
+
+        let grid = Error::from(grid::error::Error::UnexpectedChar('?'));
+        assert_eq!(grid.to_string(), "unexpected character '?'");
+        assert_eq!(
+            grid.diagnostic(Path::new("in.txt")),
+            "in.txt: unexpected character '?'"
+        );
+    }
+}
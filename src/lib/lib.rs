@@ -1,2 +1,5 @@
+pub mod error;
 pub mod solutions;
 pub mod utils;
+
+pub use error::{Error, Result};
@@ -1,5 +1,6 @@
+use crate::utils::grid::SparseGrid;
 use crate::utils::solution::Solution;
-use std::collections::HashSet;
+use glam::IVec2;
 
 pub struct Day03 {}
 
@@ -14,27 +15,27 @@ impl Solution for Day03 {
 
     fn part_two(&self, input: &str) -> Option<String> {
         let directions = input.chars().map(|ch| Direction::try_from(ch).unwrap());
-        let santa = unique_houses(directions.clone().step_by(2));
+        let mut santa = unique_houses(directions.clone().step_by(2));
         let robosanta = unique_houses(directions.skip(1).step_by(2));
 
-        let union: HashSet<&(i32, i32)> = santa.union(&robosanta).collect();
-        Some((union.len()).to_string())
+        santa.extend(robosanta.positions());
+        Some(santa.len().to_string())
     }
 }
 
-fn unique_houses<T: Iterator<Item = Direction>>(it: T) -> HashSet<(i32, i32)> {
-    let mut location = (0, 0);
-    let mut visited: HashSet<(i32, i32)> = HashSet::new();
-    visited.insert(location);
+fn unique_houses<T: Iterator<Item = Direction>>(it: T) -> SparseGrid<()> {
+    let mut location = IVec2::ZERO;
+    let mut visited = SparseGrid::new();
+    visited.insert(location, ());
 
     for direction in it {
-        location = match direction {
-            Direction::Up => (location.0, location.1 + 1),
-            Direction::Down => (location.0, location.1 - 1),
-            Direction::Left => (location.0 - 1, location.1),
-            Direction::Right => (location.0 + 1, location.1),
+        location += match direction {
+            Direction::Up => IVec2::Y,
+            Direction::Down => IVec2::NEG_Y,
+            Direction::Left => IVec2::NEG_X,
+            Direction::Right => IVec2::X,
         };
-        visited.insert(location);
+        visited.insert(location, ());
     }
 
     visited
@@ -61,4 +62,21 @@ impl TryFrom<char> for Direction {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn directions(s: &str) -> impl Iterator<Item = Direction> + Clone + '_ {
+        s.chars().map(|ch| Direction::try_from(ch).unwrap())
+    }
+
+    #[test]
+    fn examples() {
+        assert_eq!(unique_houses(directions(">")).len(), 2);
+        assert_eq!(unique_houses(directions("^>v<")).len(), 4);
+        assert_eq!(unique_houses(directions("^v^v^v^v^v")).len(), 2);
+        assert_eq!(Day03 {}.part_two("^v^v^v^v^v"), Some("11".to_string()));
+    }
+}
+
 crate::verify!(Day03, crate::my_input!("2015", "03"), "2565", "2639");
@@ -1,4 +1,7 @@
+use crate::utils::graph::traversal;
+use crate::utils::parse;
 use crate::utils::solution::Solution;
+use crate::Error;
 use std::{collections::HashMap, str::FromStr};
 
 pub struct Day07 {}
@@ -9,17 +12,26 @@ impl Solution for Day07 {
     fn part_one(&self, input: &str) -> Option<String> {
         Some(
             Wires::from_str(input)
-                .unwrap()
-                .get("a".to_string())
+                .ok()?
+                .get("a".to_string())?
                 .to_string(),
         )
     }
 
     fn part_two(&self, input: &str) -> Option<String> {
-        let mut wires = Wires::from_str(input).unwrap();
-        let value = wires.clone().get("a".to_string());
+        let mut wires = Wires::from_str(input).ok()?;
+        let value = wires.clone().get("a".to_string())?;
         wires.0.insert("b".to_string(), value.into());
-        Some(wires.eval(Operand::Name("a".to_string())).to_string())
+        Some(wires.eval(Operand::Name("a".to_string()))?.to_string())
+    }
+
+    fn diagnose(&self, input: &str) -> Vec<Error> {
+        let connections = parse::lines(input, parse_connection);
+        if !connections.is_clean() {
+            return connections.errors;
+        }
+
+        Wires::from_str(input).err().into_iter().collect()
     }
 }
 
@@ -27,45 +39,78 @@ impl Solution for Day07 {
 struct Wires(HashMap<String, Operation>);
 
 impl Wires {
-    fn get(&mut self, s: String) -> Number {
+    fn get(&mut self, s: String) -> Option<Number> {
         self.eval(Operand::Name(s))
     }
 
-    fn eval(&mut self, op: Operand) -> Number {
+    /// Evaluates `op`, caching the signal of every wire along the way.
+    /// Returns `None` for an undefined wire or a shift of 16 or more bits.
+    fn eval(&mut self, op: Operand) -> Option<Number> {
         match op {
-            Operand::Value(value) => value,
+            Operand::Value(value) => Some(value),
             Operand::Name(s) => {
-                let result = match self.0.get(&s).cloned().unwrap() {
-                    Operation::And(a, b) => self.eval(a) & self.eval(b),
-                    Operation::Assign(a) => self.eval(a),
-                    Operation::Not(a) => !self.eval(a),
-                    Operation::Or(a, b) => self.eval(a) | self.eval(b),
-                    Operation::ShiftL(a, b) => self.eval(a) << self.eval(b),
-                    Operation::ShiftR(a, b) => self.eval(a) >> self.eval(b),
+                let result = match self.0.get(&s).cloned()? {
+                    Operation::And(a, b) => self.eval(a)? & self.eval(b)?,
+                    Operation::Assign(a) => self.eval(a)?,
+                    Operation::Not(a) => !self.eval(a)?,
+                    Operation::Or(a, b) => self.eval(a)? | self.eval(b)?,
+                    Operation::ShiftL(a, b) => self.eval(a)?.checked_shl(self.eval(b)?.into())?,
+                    Operation::ShiftR(a, b) => self.eval(a)?.checked_shr(self.eval(b)?.into())?,
                 };
 
                 self.0.insert(s, result.into());
-                result
+                Some(result)
             }
         }
     }
 }
 
 impl FromStr for Wires {
-    type Err = &'static str;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut wires = HashMap::new();
+        let wires: HashMap<_, _> = parse::lines(s, parse_connection)
+            .strict()?
+            .into_iter()
+            .collect();
+
+        // Evaluating a wire that feeds back into itself would never finish
+        let inputs = |wire: &&String| {
+            wires
+                .get(*wire)
+                .map(|op| op.inputs().collect::<Vec<_>>())
+                .unwrap_or_default()
+        };
+        if traversal::find_cycle(wires.keys(), inputs).is_some() {
+            return Err(Error::InvalidInput("Circuit contains a feedback loop"));
+        }
+
+        let undriven = |wire: &String| !wires.contains_key(wire);
+        if wires.values().flat_map(Operation::inputs).any(undriven) {
+            return Err(Error::InvalidInput("Circuit reads an undriven wire"));
+        }
 
-        for line in s.lines() {
-            let (op, destination) = line.split_once(" -> ").unwrap();
-            wires.insert(destination.to_string(), Operation::from_str(op).unwrap());
+        // Shifting a 16-bit signal by a constant 16 or more bits always fails
+        let overshifts = |op: &Operation| match op {
+            Operation::ShiftL(_, Operand::Value(bits))
+            | Operation::ShiftR(_, Operand::Value(bits)) => *bits >= Number::BITS as Number,
+            _ => false,
+        };
+        if wires.values().any(overshifts) {
+            return Err(Error::InvalidInput("Shift exceeds the 16-bit signal width"));
         }
 
         Ok(Wires(wires))
     }
 }
 
+/// Parses a line such as `x AND y -> d` into the wire it drives and the
+/// operation driving it
+fn parse_connection(line: &str) -> Result<(String, Operation), &'static str> {
+    let (op, destination) = line.split_once(" -> ").ok_or("Missing ` -> `")?;
+    Ok((destination.to_string(), Operation::from_str(op)?))
+}
+
 #[derive(Clone, Debug)]
 enum Operand {
     Value(Number),
@@ -98,6 +143,26 @@ enum Operation {
     ShiftR(Operand, Operand),
 }
 
+impl Operation {
+    /// Names of the wires this operation reads
+    fn inputs(&self) -> impl Iterator<Item = &String> {
+        let operands = match self {
+            Self::Assign(a) | Self::Not(a) => [Some(a), None],
+            Self::And(a, b) | Self::Or(a, b) | Self::ShiftL(a, b) | Self::ShiftR(a, b) => {
+                [Some(a), Some(b)]
+            }
+        };
+
+        operands
+            .into_iter()
+            .flatten()
+            .filter_map(|operand| match operand {
+                Operand::Name(name) => Some(name),
+                Operand::Value(_) => None,
+            })
+    }
+}
+
 impl From<Number> for Operation {
     fn from(value: Number) -> Self {
         Self::Assign(Operand::Value(value))
@@ -110,22 +175,22 @@ impl FromStr for Operation {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let tokens = s.split_ascii_whitespace().collect::<Vec<_>>();
 
-        Ok(match tokens.len() {
-            1 => Operation::Assign(Operand::from_str(tokens[0]).unwrap()),
-            2 => Operation::Not(Operand::from_str(tokens[1]).unwrap()),
-            3 => {
-                let lhs = Operand::from_str(tokens[0]).unwrap();
-                let rhs = Operand::from_str(tokens[2]).unwrap();
+        Ok(match tokens[..] {
+            [value] => Operation::Assign(Operand::from_str(value)?),
+            ["NOT", value] => Operation::Not(Operand::from_str(value)?),
+            [lhs, op, rhs] => {
+                let lhs = Operand::from_str(lhs)?;
+                let rhs = Operand::from_str(rhs)?;
 
-                match tokens[1] {
+                match op {
                     "AND" => Operation::And(lhs, rhs),
                     "OR" => Operation::Or(lhs, rhs),
                     "LSHIFT" => Operation::ShiftL(lhs, rhs),
                     "RSHIFT" => Operation::ShiftR(lhs, rhs),
-                    _ => unreachable!(),
+                    _ => Err("Unknown operator")?,
                 }
             }
-            _ => unreachable!(),
+            _ => Err("Expected 1 to 3 tokens")?,
         })
     }
 }
@@ -133,6 +198,7 @@ impl FromStr for Operation {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn example_input() {
@@ -147,14 +213,90 @@ NOT y -> i";
 
         let mut wires = Wires::from_str(INPUT).unwrap();
 
-        assert_eq!(wires.eval(Operand::Name("d".to_string())), 72);
-        assert_eq!(wires.eval(Operand::Name("e".to_string())), 507);
-        assert_eq!(wires.eval(Operand::Name("f".to_string())), 492);
-        assert_eq!(wires.eval(Operand::Name("g".to_string())), 114);
-        assert_eq!(wires.eval(Operand::Name("h".to_string())), 65412);
-        assert_eq!(wires.eval(Operand::Name("i".to_string())), 65079);
-        assert_eq!(wires.eval(Operand::Name("x".to_string())), 123);
-        assert_eq!(wires.eval(Operand::Name("y".to_string())), 456);
+        assert_eq!(wires.eval(Operand::Name("d".to_string())), Some(72));
+        assert_eq!(wires.eval(Operand::Name("e".to_string())), Some(507));
+        assert_eq!(wires.eval(Operand::Name("f".to_string())), Some(492));
+        assert_eq!(wires.eval(Operand::Name("g".to_string())), Some(114));
+        assert_eq!(wires.eval(Operand::Name("h".to_string())), Some(65412));
+        assert_eq!(wires.eval(Operand::Name("i".to_string())), Some(65079));
+        assert_eq!(wires.eval(Operand::Name("x".to_string())), Some(123));
+        assert_eq!(wires.eval(Operand::Name("y".to_string())), Some(456));
+    }
+
+    #[test]
+    fn malformed_input() {
+        assert!(Operation::from_str("").is_err());
+        assert!(Operation::from_str("x XOR y").is_err());
+        assert!(Operation::from_str("x AND y z").is_err());
+        assert!(Operation::from_str("AND x").is_err());
+        assert!(Wires::from_str("123 x").is_err());
+        assert!(Wires::from_str("x NAND y -> z").is_err());
+        assert!(Wires::from_str("x -> y\nNOT y -> z\nz AND 1 -> x").is_err());
+    }
+
+    #[test]
+    fn unevaluable_circuits() {
+        for input in [
+            "x -> a",
+            "1 -> b",
+            "1 -> b\nb LSHIFT 20 -> a",
+            "1 -> b\nb RSHIFT b -> c\nc LSHIFT 16 -> a",
+        ] {
+            assert_eq!(Day07 {}.part_one(input), None, "{:?}", input);
+            assert_eq!(Day07 {}.part_two(input), None, "{:?}", input);
+        }
+        assert!(Wires::from_str("x -> a").is_err());
+        assert!(Wires::from_str("1 LSHIFT 16 -> a").is_err());
+        assert!(Wires::from_str("1 RSHIFT 15 -> a").is_ok());
+    }
+
+    #[test]
+    fn diagnostics() {
+        assert_eq!(
+            Day07 {}.diagnose("1 -> a\nx XOR y -> b\nNOT x y z -> c"),
+            vec![
+                Error::at_line(2, "x XOR y -> b", "Unknown operator"),
+                Error::at_line(3, "NOT x y z -> c", "Expected 1 to 3 tokens"),
+            ]
+        );
+        assert_eq!(
+            Day07 {}.diagnose("b -> a\na -> b"),
+            vec![Error::InvalidInput("Circuit contains a feedback loop")]
+        );
+        assert_eq!(
+            Day07 {}.diagnose("x AND 1 -> a"),
+            vec![Error::InvalidInput("Circuit reads an undriven wire")]
+        );
+        assert!(Day07 {}.diagnose("1 -> a").is_empty());
+    }
+
+    proptest! {
+        #[test]
+        fn operation_never_panics(s in "\\PC*") {
+            let _ = Operation::from_str(&s);
+        }
+
+        #[test]
+        fn operation_tokens_never_panic(s in "((NOT|AND|OR|LSHIFT|RSHIFT|[a-z]{1,2}|[0-9]{1,6}) ?){0,4}") {
+            let _ = Operation::from_str(&s);
+        }
+
+        #[test]
+        fn wires_never_panic(s in "\\PC*( -> \\PC*)?") {
+            let _ = Wires::from_str(&s);
+        }
+
+        #[test]
+        fn circuits_never_panic(
+            lines in prop::collection::vec(
+                "((NOT )?([a-c]|[0-9]{1,5})|([a-c]|[0-9]{1,5}) (AND|OR|LSHIFT|RSHIFT) ([a-c]|[0-9]{1,5})) -> [a-c]",
+                0..6,
+            )
+        ) {
+            let input = lines.join("\n");
+            let _ = Day07 {}.part_one(&input);
+            let _ = Day07 {}.part_two(&input);
+        }
     }
 }
 
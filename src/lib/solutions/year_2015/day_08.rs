@@ -57,10 +57,10 @@ mod tests {
 
     #[test]
     fn example_lengths() {
-        assert_eq!(length_difference(&r#""#), 2);
-        assert_eq!(length_difference(&r#"abc"#), 2);
-        assert_eq!(length_difference(&r#"aaa\"aaa"#), 3);
-        assert_eq!(length_difference(&r#"\x27"#), 5);
+        assert_eq!(length_difference(r#""#), 2);
+        assert_eq!(length_difference(r#"abc"#), 2);
+        assert_eq!(length_difference(r#"aaa\"aaa"#), 3);
+        assert_eq!(length_difference(r#"\x27"#), 5);
     }
 
     #[test]
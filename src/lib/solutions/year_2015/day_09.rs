@@ -1,23 +1,37 @@
-use itertools::Itertools;
-use std::collections::{HashMap, HashSet};
-
+use crate::utils::graph::{Directedness, Edge, EdgeList};
+use crate::utils::parse;
 use crate::utils::solution::Solution;
+use crate::Error;
 
 pub struct Day09 {}
 
 impl Solution for Day09 {
     fn part_one(&self, input: &str) -> Option<String> {
-        match distance_bounds(input) {
-            itertools::MinMaxResult::MinMax(a, _) => Some(a.to_string()),
-            _ => panic!(),
-        }
+        let (_, distance) = routes(input).shortest_acyclic_tour_by(either_direction)?;
+        Some(distance.to_string())
     }
 
     fn part_two(&self, input: &str) -> Option<String> {
-        match distance_bounds(input) {
-            itertools::MinMaxResult::MinMax(_, b) => Some(b.to_string()),
-            _ => panic!(),
-        }
+        let (_, distance) = routes(input).longest_acyclic_tour_by(either_direction)?;
+        Some(distance.to_string())
+    }
+
+    /// Routes between locations with the shortest tour highlighted
+    fn dot(&self, input: &str) -> Option<String> {
+        let routes = routes(input);
+        let (tour, _) = routes.shortest_acyclic_tour_by(either_direction)?;
+        let dot = routes
+            .dot()
+            .with_directedness(Directedness::Undirected)
+            .display_labels()
+            .edge_labels()
+            .highlight_path(tour);
+
+        Some(dot.to_string())
+    }
+
+    fn diagnose(&self, input: &str) -> Vec<Error> {
+        parse::lines(input, LocationPair::try_from).errors
     }
 }
 
@@ -29,40 +43,72 @@ struct LocationPair<'a> {
 }
 
 impl<'a> TryFrom<&'a str> for LocationPair<'a> {
-    type Error = Box<dyn std::error::Error>;
+    type Error = Error;
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
         let mut tokens = s.split_ascii_whitespace();
 
         Ok(LocationPair {
-            from: tokens.next().ok_or("Length error")?,
-            to: tokens.nth(1).ok_or("Length error")?,
-            distance: tokens.nth(1).ok_or("length error")?.parse::<i32>()?,
+            from: tokens.next().ok_or("Missing origin")?,
+            to: tokens.nth(1).ok_or("Missing destination")?,
+            distance: tokens.nth(1).ok_or("Missing distance")?.parse::<i32>()?,
         })
     }
 }
 
-fn distance_bounds(input: &str) -> itertools::MinMaxResult<i32> {
-    let mut places: HashSet<&str> = HashSet::new();
-    let mut distances: HashMap<&str, HashMap<&str, i32>> = HashMap::new();
+fn routes(input: &str) -> EdgeList<&str, i32> {
+    parse::lines(input, LocationPair::try_from)
+        .values
+        .into_iter()
+        .map(|LocationPair { from, to, distance }| Edge::with_data(from, to, distance))
+        .collect::<Vec<_>>()
+        .into()
+}
+
+/// Distances are listed once per pair of locations but apply both ways
+fn either_direction(fwd: Option<i32>, bkwd: Option<i32>) -> Option<i32> {
+    fwd.or(bkwd)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    for LocationPair { from, to, distance } in input.lines().flat_map(LocationPair::try_from) {
-        places.insert(from);
-        places.insert(to);
+    const INPUT: &str = "London to Dublin = 464
+London to Belfast = 518
+Dublin to Belfast = 141";
 
-        distances.entry(from).or_default().insert(to, distance);
-        distances.entry(to).or_default().insert(from, distance);
+    #[test]
+    fn example() {
+        assert_eq!(Day09 {}.part_one(INPUT), Some("605".to_string()));
+        assert_eq!(Day09 {}.part_two(INPUT), Some("982".to_string()));
     }
 
-    places
-        .iter()
-        .permutations(places.len())
-        .map(|v| {
-            v.iter()
-                .tuple_windows::<(_, _)>()
-                .map(|(&&a, &&b)| distances.get(a).unwrap().get(b).unwrap())
-                .sum::<i32>()
-        })
-        .minmax()
+    #[test]
+    fn shortest_route_graph() {
+        let dot = Day09 {}.dot(INPUT).unwrap();
+
+        assert!(dot.starts_with("graph {"));
+        assert!(dot.contains("n0 -- n1 [label=\"464\", color=red, penwidth=2];"));
+        assert!(dot.contains("n0 -- n2 [label=\"518\"];"));
+    }
+
+    #[test]
+    fn skipped_lines() {
+        let input = format!("{}\nCork to Dublin\nCork to Belfast = far", INPUT);
+
+        assert_eq!(Day09 {}.part_one(&input), Some("605".to_string()));
+        assert_eq!(
+            Day09 {}.diagnose(&input),
+            vec![
+                Error::at_line(4, "Cork to Dublin", "invalid input: Missing distance"),
+                Error::at_line(
+                    5,
+                    "Cork to Belfast = far",
+                    "failed to parse value: invalid digit found in string"
+                ),
+            ]
+        );
+    }
 }
 
 crate::verify!(Day09, crate::my_input!("2015", "09"), "141", "736");
@@ -50,10 +50,7 @@ impl Password {
     }
 
     fn has_no_invalid_chars(&self) -> bool {
-        !self
-            .0
-            .iter()
-            .any(|&n| (n == b'i' || n == b'o' || n == b'l'))
+        !self.0.iter().any(|&n| n == b'i' || n == b'o' || n == b'l')
     }
 
     fn has_pairs(&self) -> bool {
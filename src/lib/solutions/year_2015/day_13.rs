@@ -8,9 +8,9 @@ pub struct Day13 {}
 impl Solution for Day13 {
     fn part_one(&self, input: &str) -> Option<String> {
         let (_, happiness) = graph::EdgeList::from(input.lines().map(to_edge))
-            .shortest_cyclic_tour_by(pairwise_happiness);
+            .longest_cyclic_tour_by(pairwise_happiness)?;
 
-        Some(happiness.abs().to_string())
+        Some(happiness.to_string())
     }
 
     fn part_two(&self, input: &str) -> Option<String> {
@@ -30,9 +30,23 @@ impl Solution for Day13 {
         }));
 
         let (_, happiness) =
-            graph::EdgeList::from(edges).shortest_cyclic_tour_by(pairwise_happiness);
+            graph::EdgeList::from(edges).longest_cyclic_tour_by(pairwise_happiness)?;
 
-        Some(happiness.abs().to_string())
+        Some(happiness.to_string())
+    }
+
+    /// Happiness of each guest beside each other guest, with the happiest
+    /// seating arrangement highlighted
+    fn dot(&self, input: &str) -> Option<String> {
+        let guests = graph::EdgeList::from(input.lines().map(to_edge));
+        let (seating, _) = guests.longest_cyclic_tour_by(pairwise_happiness)?;
+        let dot = guests
+            .dot()
+            .display_labels()
+            .edge_labels()
+            .highlight_path(seating);
+
+        Some(dot.to_string())
     }
 }
 
@@ -53,7 +67,7 @@ fn to_edge(s: &str) -> graph::Edge<String, i32> {
 
 fn pairwise_happiness(fwd: Option<i32>, bkwd: Option<i32>) -> Option<i32> {
     match (fwd, bkwd) {
-        (Some(f), Some(b)) => Some(-(f + b)),
+        (Some(f), Some(b)) => Some(f + b),
         _ => None,
     }
 }
@@ -78,8 +92,8 @@ David would lose 7 happiness units by sitting next to Bob.
 David would gain 41 happiness units by sitting next to Carol.";
         let graph = graph::EdgeList::from(INPUT.lines().map(to_edge));
 
-        let (_, happiness) = graph.shortest_cyclic_tour_by(pairwise_happiness);
-        assert_eq!(happiness.abs(), 330);
+        let (_, happiness) = graph.longest_cyclic_tour_by(pairwise_happiness).unwrap();
+        assert_eq!(happiness, 330);
     }
 }
 
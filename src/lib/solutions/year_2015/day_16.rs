@@ -52,12 +52,10 @@ impl Sue {
 
     fn is_valid_part_2(&self, tape: &HashMap<String, i32>) -> bool {
         self.0.iter().all(|(key, value)| match key.as_str() {
-            "cats" | "trees" => tape
-                .get(key)
-                .map_or(false, |&tape_value| *value > tape_value),
-            "pomeranians" | "goldfish" => tape
-                .get(key)
-                .map_or(false, |&tape_value| *value < tape_value),
+            "cats" | "trees" => tape.get(key).is_some_and(|&tape_value| *value > tape_value),
+            "pomeranians" | "goldfish" => {
+                tape.get(key).is_some_and(|&tape_value| *value < tape_value)
+            }
             _ => tape.get(key) == Some(value),
         })
     }
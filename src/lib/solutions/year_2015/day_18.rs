@@ -1,132 +1,133 @@
-use std::str::FromStr;
-
+use crate::utils::automaton::Automaton;
+use crate::utils::grid::{self, Boundary, Connectivity, Grid, Location};
 use crate::utils::solution::Solution;
 
 pub struct Day18 {}
 
 impl Solution for Day18 {
     fn part_one(&self, input: &str) -> Option<String> {
-        let mut grid = Grid::from_str(input).unwrap();
-        for _ in 0..100 {
-            grid.update();
-        }
-
-        Some(grid.total().to_string())
+        let lights = parse_lights(input).ok()?;
+        Some(animate(lights, 100, conway).to_string())
     }
 
     fn part_two(&self, input: &str) -> Option<String> {
-        let mut grid = Grid::from_str(input).unwrap();
-        for _ in 0..100 {
-            grid.set_corners(Light::On);
-            grid.update();
+        let mut lights = parse_lights(input).ok()?;
+        for corner in corners(&lights) {
+            lights[corner] = Light::On;
         }
 
-        grid.set_corners(Light::On);
-        Some(grid.total().to_string())
+        Some(animate(lights, 100, stuck_corners).to_string())
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum Light {
     On,
     Off,
 }
 
-impl From<char> for Light {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for Light {
+    type Error = grid::error::Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            '#' => Self::On,
-            '.' => Self::Off,
-            _ => panic!("Invalid light character"),
+            '#' => Ok(Self::On),
+            '.' => Ok(Self::Off),
+            _ => Err(Self::Error::UnexpectedChar(value)),
         }
     }
 }
 
-type Index = usize;
+type Lights = Grid<Light>;
 
-#[derive(Clone, Debug)]
-struct Grid {
-    size: usize,
-    cells: Vec<Light>,
+fn parse_lights(s: &str) -> grid::Result<Lights> {
+    Grid::from_str_with(s, Light::try_from)
 }
 
-impl Grid {
-    fn index(&self, row: Index, col: Index) -> Index {
-        (row % self.size) * self.size + (col % self.size)
+/// Runs `rule` for `steps` generations and counts the lights left on
+fn animate(lights: Lights, steps: usize, rule: fn(&Lights, Location) -> Light) -> usize {
+    let mut automaton = Automaton::new(lights, rule);
+    for _ in 0..steps {
+        automaton.step();
     }
 
-    fn count_neighbors(&self, row: Index, col: Index) -> i32 {
-        let mut count = 0;
-        for r in (row as isize - 1).max(0)..=(row as isize + 1).min((self.size - 1) as isize) {
-            for c in (col as isize - 1).max(0)..=(col as isize + 1).min((self.size - 1) as isize) {
-                if r as Index == row && c as Index == col {
-                    continue;
-                }
-
-                count += match self.cells[self.index(r as Index, c as Index)] {
-                    Light::Off => 0,
-                    Light::On => 1,
-                }
-            }
-        }
+    automaton
+        .cells()
+        .iter()
+        .filter(|&&light| light == Light::On)
+        .count()
+}
+
+fn conway(lights: &Lights, location: Location) -> Light {
+    let neighbors = lights
+        .neighbors(location, Connectivity::Moore, Boundary::Clip)
+        .filter(|&neighbor| lights[neighbor] == Light::On)
+        .count();
 
-        count
+    match (lights[location], neighbors) {
+        (Light::On, 2..=3) => Light::On,
+        (Light::Off, 3) => Light::On,
+        _ => Light::Off,
     }
+}
 
-    fn update(&mut self) {
-        let mut new_cells = self.cells.clone();
-        for col in 0..self.size {
-            for row in 0..self.size {
-                let idx = self.index(row, col);
-
-                let cell = self.cells[idx];
-                let neighbors = self.count_neighbors(row, col);
-                new_cells[idx] = match (cell, neighbors) {
-                    (Light::On, 2..=3) => Light::On,
-                    (Light::Off, 3) => Light::On,
-                    _ => Light::Off,
-                };
-            }
-        }
-        self.cells = new_cells;
+fn stuck_corners(lights: &Lights, location: Location) -> Light {
+    if corners(lights).contains(&location) {
+        Light::On
+    } else {
+        conway(lights, location)
     }
+}
+
+/// Locations of the four corners, of which an empty grid has none
+fn corners(lights: &Lights) -> Vec<Location> {
+    let (Some(r), Some(c)) = (lights.rows().checked_sub(1), lights.cols().checked_sub(1)) else {
+        return Vec::new();
+    };
 
-    fn set_corners(&mut self, state: Light) {
-        let n = self.size - 1;
-        let (a, b, c, d) = (
-            self.index(0, 0),
-            self.index(0, n),
-            self.index(n, 0),
-            self.index(n, n),
-        );
-
-        self.cells[a] = state;
-        self.cells[b] = state;
-        self.cells[c] = state;
-        self.cells[d] = state;
+    [(0, 0), (0, c), (r, 0), (r, c)]
+        .map(Location::from)
+        .to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = ".#.#.#
+...##.
+#....#
+..#...
+#.#..#
+####..";
+
+    #[test]
+    fn example() {
+        let lights = parse_lights(INPUT).unwrap();
+        assert_eq!(animate(lights, 4, conway), 4);
     }
 
-    fn total(&self) -> i32 {
-        self.cells.iter().fold(0, |acc, cell| {
-            acc + match cell {
-                Light::Off => 0,
-                Light::On => 1,
-            }
-        })
+    #[test]
+    fn no_steps() {
+        let lights = parse_lights(INPUT).unwrap();
+        assert_eq!(animate(lights, 0, conway), 15);
     }
-}
 
-impl std::str::FromStr for Grid {
-    type Err = &'static str;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self {
-            size: s.lines().next().unwrap().len(),
-            cells: s
-                .lines()
-                .flat_map(|line| line.chars().map(Light::from))
-                .collect(),
-        })
+    #[test]
+    fn zero_width_grid() {
+        let lights = Grid::filled(grid::Dimensions::new(3, 0), Light::Off);
+        assert!(corners(&lights).is_empty());
+        assert_eq!(animate(lights, 2, stuck_corners), 0);
+    }
+
+    #[test]
+    fn example_with_stuck_corners() {
+        let mut lights = parse_lights(INPUT).unwrap();
+        for corner in corners(&lights) {
+            lights[corner] = Light::On;
+        }
+
+        assert_eq!(animate(lights, 5, stuck_corners), 17);
     }
 }
 
@@ -1,6 +1,6 @@
-use std::{cmp::Ordering, collections::BinaryHeap};
-
+use crate::utils::graph::dijkstra::{self, Node};
 use crate::utils::solution::Solution;
+use std::str::FromStr;
 
 const DEFAULT_PLAYER_HEALTH: i32 = 50;
 const DEFAULT_PLAYER_MANA: i32 = 500;
@@ -8,267 +8,220 @@ const DEFAULT_PLAYER_MANA: i32 = 500;
 pub struct Day22 {}
 
 impl Solution for Day22 {
-    fn part_one(&self, _input: &str) -> Option<String> {
-        let mut heap = BinaryHeap::<Node>::new();
-
-        let start = Node::new(PlayerState::new(14, 250), BossState::new(10, 9));
-
-        println!("START: {:#?}", start);
-
-        for (i, item) in start.neighbors().iter().enumerate() {
-            println!("\tNEIGHBOR {}: {:#?}", i, item);
-        }
-
-        for (j, item) in start
-            .neighbors()
-            .last()
-            .unwrap()
-            .neighbors()
-            .iter()
-            .enumerate()
-        {
-            println!("\t\tNEIGHBOR 4.{}: {:#?}", j, item);
-        }
+    fn part_one(&self, input: &str) -> Option<String> {
+        let boss = Boss::from_str(input).ok()?;
+        let battle = Battle::new(DEFAULT_PLAYER_HEALTH, DEFAULT_PLAYER_MANA, boss, false);
 
-        // heap.push(start);
-
-        // while let Some(node) = heap.pop() {
-        //     if node.game_state.boss_state.health <= 0 {
-        //         println!("{:#?}", node);
-        //         break;
-        //     }
-
-        //     heap.extend(node.neighbors());
-        // }
-
-        todo!()
+        dijkstra::a_star(battle).map(|mana| mana.to_string())
     }
 
-    fn part_two(&self, _input: &str) -> Option<String> {
-        None
+    fn part_two(&self, input: &str) -> Option<String> {
+        let boss = Boss::from_str(input).ok()?;
+        let battle = Battle::new(DEFAULT_PLAYER_HEALTH, DEFAULT_PLAYER_MANA, boss, true);
+
+        dijkstra::a_star(battle).map(|mana| mana.to_string())
     }
 }
 
-#[derive(Clone, Debug)]
-struct Spell {
-    name: &'static str,
-    cost: i32,
-    duration: i32,
-    cast_effect: fn(&Spell, GameState) -> GameState,
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Spell {
+    MagicMissile,
+    Drain,
+    Shield,
+    Poison,
+    Recharge,
 }
 
+const SPELLS: [Spell; 5] = [
+    Spell::MagicMissile,
+    Spell::Drain,
+    Spell::Shield,
+    Spell::Poison,
+    Spell::Recharge,
+];
+
 impl Spell {
-    const fn new(
-        name: &'static str,
-        cost: i32,
-        duration: i32,
-        cast_effect: fn(&Spell, GameState) -> GameState,
-    ) -> Self {
-        Self {
-            name,
-            cost,
-            duration,
-            cast_effect,
+    fn cost(&self) -> i32 {
+        match self {
+            Self::MagicMissile => 53,
+            Self::Drain => 73,
+            Self::Shield => 113,
+            Self::Poison => 173,
+            Self::Recharge => 229,
         }
     }
 }
 
-const SPELLS: [Spell; 5] = [
-    Spell::new("Magic missile", 53, 0, |spell, mut game_state| {
-        game_state.player_state.mana -= spell.cost;
-        game_state.boss_state.health -= 4;
-
-        game_state
-    }),
-    Spell::new("Drain", 73, 0, |spell, mut game_state| {
-        game_state.player_state.mana -= spell.cost;
-        game_state.boss_state.health -= 2;
-        game_state.player_state.health += 2;
-
-        game_state
-    }),
-    Spell::new("Shield", 113, 6, |spell, mut game_state| {
-        game_state.player_state.mana -= spell.cost;
-        game_state.active_spells.push(SpellEffect {
-            name: spell.name.to_string(),
-            duration: spell.duration,
-            effect: |name, mut game_state| {
-                if let Some(spell_state) = game_state.find_active_spell_by_name(name) {
-                    spell_state.duration -= 1;
-                    game_state.player_state.armor = spell_state.duration.signum() * 7;
-                }
-
-                game_state
-            },
-        });
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Boss {
+    health: i32,
+    damage: i32,
+}
 
-        game_state
-    }),
-    Spell::new("Poison", 173, 6, |spell, mut game_state| {
-        game_state.player_state.mana -= spell.cost;
-        game_state.active_spells.push(SpellEffect {
-            name: spell.name.to_string(),
-            duration: spell.duration,
-            effect: |name, mut game_state| {
-                if let Some(spell_state) = game_state.find_active_spell_by_name(name) {
-                    spell_state.duration -= 1;
-                    game_state.boss_state.health -= 3;
-                }
-
-                game_state
-            },
-        });
+impl FromStr for Boss {
+    type Err = &'static str;
 
-        game_state
-    }),
-    Spell::new("Recharge", 229, 5, |spell, mut game_state| {
-        game_state.player_state.mana -= spell.cost;
-        game_state.active_spells.push(SpellEffect {
-            name: spell.name.to_string(),
-            duration: spell.duration,
-            effect: |name, mut game_state| {
-                if let Some(spell_state) = game_state.find_active_spell_by_name(name) {
-                    spell_state.duration -= 1;
-                    game_state.player_state.mana += 101;
-                }
-
-                game_state
-            },
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut values = s.lines().map(|line| {
+            line.split_once(':')
+                .and_then(|(_, value)| value.trim().parse::<i32>().ok())
+                .ok_or("Expected `<Stat>: <value>`")
         });
 
-        game_state
-    }),
-];
+        Ok(Self {
+            health: values.next().ok_or("Missing boss hit points")??,
+            damage: values.next().ok_or("Missing boss damage")??,
+        })
+    }
+}
 
-#[derive(Clone, Copy, Debug)]
-struct PlayerState {
+/// State of the fight at the start of one of the player's turns. Effect
+/// timers count the turns remaining for each active effect.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Battle {
     health: i32,
-    armor: i32,
     mana: i32,
+    boss: Boss,
+    shield: i32,
+    poison: i32,
+    recharge: i32,
+    hard: bool,
 }
 
-impl PlayerState {
-    fn new(health: i32, mana: i32) -> Self {
-        PlayerState {
+impl Battle {
+    fn new(health: i32, mana: i32, boss: Boss, hard: bool) -> Self {
+        Self {
             health,
-            armor: 0,
             mana,
+            boss,
+            shield: 0,
+            poison: 0,
+            recharge: 0,
+            hard,
         }
     }
-}
 
-#[derive(Clone, Copy, Debug)]
-struct BossState {
-    health: i32,
-    damage: i32,
-}
+    /// Applies active effects at the start of either combatant's turn,
+    /// returning the player's armor for that turn
+    fn apply_effects(&mut self) -> i32 {
+        let armor = if self.shield > 0 { 7 } else { 0 };
+        if self.poison > 0 {
+            self.boss.health -= 3;
+        }
+        if self.recharge > 0 {
+            self.mana += 101;
+        }
 
-impl BossState {
-    fn new(health: i32, damage: i32) -> Self {
-        BossState { health, damage }
+        self.shield = (self.shield - 1).max(0);
+        self.poison = (self.poison - 1).max(0);
+        self.recharge = (self.recharge - 1).max(0);
+        armor
     }
-}
 
-#[derive(Clone, Debug)]
-struct SpellEffect {
-    name: String,
-    duration: i32,
-    effect: fn(&str, GameState) -> GameState,
-}
+    /// Casts `spell`, or returns `false` if it is unaffordable or its effect
+    /// is still active
+    fn cast(&mut self, spell: Spell) -> bool {
+        if spell.cost() > self.mana {
+            return false;
+        }
 
-#[derive(Clone, Debug)]
-struct GameState {
-    player_state: PlayerState,
-    boss_state: BossState,
-    active_spells: Vec<SpellEffect>,
-}
+        match spell {
+            Spell::MagicMissile => self.boss.health -= 4,
+            Spell::Drain => {
+                self.boss.health -= 2;
+                self.health += 2;
+            }
+            Spell::Shield if self.shield == 0 => self.shield = 6,
+            Spell::Poison if self.poison == 0 => self.poison = 6,
+            Spell::Recharge if self.recharge == 0 => self.recharge = 5,
+            _ => return false,
+        }
 
-impl GameState {
-    fn find_active_spell_by_name(&mut self, name: &str) -> Option<&mut SpellEffect> {
-        self.active_spells.iter_mut().find(|item| item.name == name)
+        self.mana -= spell.cost();
+        true
     }
 
-    fn apply_spell_effects(self) -> Self {
-        self.active_spells
-            .iter()
-            .fold(self.clone(), |acc, active_spell| {
-                (active_spell.effect)(&active_spell.name, acc)
-            })
+    /// Plays the rest of the round after the player casts `spell`, returning
+    /// the battle at the start of the player's next turn, or as soon as the
+    /// boss dies. Returns `None` if the cast is illegal or the player dies.
+    fn round(&self, spell: Spell) -> Option<Self> {
+        let mut next = self.clone();
+        if !next.cast(spell) {
+            return None;
+        }
+        if next.is_destination() {
+            return Some(next);
+        }
+
+        let armor = next.apply_effects();
+        if next.is_destination() {
+            return Some(next);
+        }
+
+        next.health -= (next.boss.damage - armor).max(1);
+        (next.health > 0).then_some(next)
     }
 }
 
-#[derive(Clone, Debug)]
-struct Node {
-    cost: i32,
-    game_state: GameState,
-}
+impl Node for Battle {
+    type Cost = i32;
 
-impl Node {
-    /// Generates a zero-cost [`Node`] with nothing going on in-game
-    fn new(player_state: PlayerState, boss_state: BossState) -> Self {
-        Self {
-            cost: 0,
-            game_state: GameState {
-                player_state,
-                boss_state,
-                active_spells: Vec::default(),
-            },
-        }
+    fn is_destination(&self) -> bool {
+        self.boss.health <= 0
     }
 
-    fn neighbors(&self) -> Vec<Node> {
+    fn neighbors(&self) -> Vec<(Self::Cost, Self)> {
+        let mut turn = self.clone();
+        if turn.hard {
+            turn.health -= 1;
+            if turn.health <= 0 {
+                return Vec::new();
+            }
+        }
+
+        turn.apply_effects();
+        if turn.is_destination() {
+            return vec![(0, turn)];
+        }
+
         SPELLS
             .iter()
-            .filter_map(|spell| -> Option<Node> {
-                let mut next_node = self.clone();
-
-                // Apply each of the active spell effects
-                next_node.game_state = next_node.game_state.apply_spell_effects();
-
-                // Prune spells that have ended
-                next_node
-                    .game_state
-                    .active_spells
-                    .retain(|effect| effect.duration > 0);
-
-                if next_node
-                    .game_state
-                    .active_spells
-                    .iter()
-                    .any(|spell_state| spell_state.name == spell.name)
-                    || next_node.game_state.player_state.mana < spell.cost
-                {
-                    return None; // Can't have the same effect active twice
-                }
-
-                // Cast the spell in the current `map` invocation
-                next_node.cost += spell.cost;
-                next_node.game_state = (spell.cast_effect)(spell, next_node.game_state);
-
-                Some(next_node)
-            })
+            .filter_map(|&spell| turn.round(spell).map(|next| (spell.cost(), next)))
             .collect()
     }
-}
 
-impl PartialEq for Node {
-    fn eq(&self, other: &Self) -> bool {
-        self.cost == other.cost
+    /// Poison deals the most damage per point of mana, so no sequence of
+    /// spells finishes the boss more cheaply than poisoning it throughout.
+    fn heuristic(&self) -> Self::Cost {
+        let remaining = (self.boss.health - 3 * self.poison).max(0);
+        remaining * Spell::Poison.cost() / 18
     }
 }
 
-impl Eq for Node {}
+crate::verify!(Day22, crate::my_input!("2015", "22"), "", "");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-impl PartialOrd for Node {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(other.cost.cmp(&self.cost))
+    #[test]
+    fn examples() {
+        let battle = |health| Battle::new(10, 250, Boss { health, damage: 8 }, false);
+
+        assert_eq!(dijkstra::a_star(battle(13)), Some(226));
+        assert_eq!(dijkstra::a_star(battle(14)), Some(641));
+        assert_eq!(dijkstra::search(battle(14)), Some(641));
     }
-}
 
-impl Ord for Node {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.cost.cmp(&self.cost)
+    #[test]
+    fn parse_boss() {
+        assert_eq!(
+            Boss::from_str("Hit Points: 58\nDamage: 9"),
+            Ok(Boss {
+                health: 58,
+                damage: 9
+            })
+        );
+        assert!(Boss::from_str("Hit Points: 58").is_err());
     }
 }
-
-crate::verify!(Day22, crate::my_input!("2015", "22"), "", "");
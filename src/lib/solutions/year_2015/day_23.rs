@@ -1,13 +1,13 @@
+use crate::utils::parse;
 use crate::utils::solution::Solution;
-use itertools::Itertools;
+use crate::Error;
 use std::str::FromStr;
 
 pub struct Day23 {}
 
 impl Solution for Day23 {
     fn part_one(&self, input: &str) -> Option<String> {
-        let instructions = input.lines().flat_map(Instruction::from_str).collect_vec();
-        let mut machine = Machine::new((0, 0), instructions);
+        let mut machine = Machine::new((0, 0), parse::lines(input, Instruction::from_str).values);
 
         for _ in machine.by_ref() {}
 
@@ -15,13 +15,16 @@ impl Solution for Day23 {
     }
 
     fn part_two(&self, input: &str) -> Option<String> {
-        let instructions = input.lines().flat_map(Instruction::from_str).collect_vec();
-        let mut machine = Machine::new((1, 0), instructions);
+        let mut machine = Machine::new((1, 0), parse::lines(input, Instruction::from_str).values);
 
         for _ in machine.by_ref() {}
 
         Some(machine.register_b.to_string())
     }
+
+    fn diagnose(&self, input: &str) -> Vec<Error> {
+        parse::lines(input, Instruction::from_str).errors
+    }
 }
 
 type Offset = i32;
@@ -45,6 +48,17 @@ impl FromStr for Register {
     }
 }
 
+fn parse_register(token: Option<&str>) -> Result<Register, &'static str> {
+    Register::from_str(token.ok_or("Missing register")?.trim_end_matches(','))
+}
+
+fn parse_offset(token: Option<&str>) -> Result<Offset, &'static str> {
+    token
+        .ok_or("Missing offset")?
+        .parse()
+        .map_err(|_| "Invalid offset")
+}
+
 #[derive(Clone, Copy, Debug)]
 enum Instruction {
     Half(Register),
@@ -63,36 +77,16 @@ impl FromStr for Instruction {
 
         Ok(match tokens.next() {
             None => Err("Empty instruction")?,
-            Some("hlf") => {
-                Self::Half(Register::from_str(tokens.next().ok_or("Bad token get")?).unwrap())
-            }
-            Some("inc") => {
-                Self::Increment(Register::from_str(tokens.next().ok_or("Bad token get")?).unwrap())
-            }
-            Some("jmp") => Self::Jump(tokens.next().ok_or("Bad token get")?.parse().unwrap()),
+            Some("hlf") => Self::Half(parse_register(tokens.next())?),
+            Some("inc") => Self::Increment(parse_register(tokens.next())?),
+            Some("jmp") => Self::Jump(parse_offset(tokens.next())?),
             Some("jie") => {
-                if let Some(reg) = tokens.next() {
-                    Self::JumpIfEven(
-                        Register::from_str(reg.trim_end_matches(',')).unwrap(),
-                        tokens.next().ok_or("Bad token get")?.parse().unwrap(),
-                    )
-                } else {
-                    Err("Invalid jie arguments")?
-                }
+                Self::JumpIfEven(parse_register(tokens.next())?, parse_offset(tokens.next())?)
             }
             Some("jio") => {
-                if let Some(reg) = tokens.next() {
-                    Self::JumpIfOne(
-                        Register::from_str(reg.trim_end_matches(',')).unwrap(),
-                        tokens.next().ok_or("Bad token get")?.parse().unwrap(),
-                    )
-                } else {
-                    Err("Invalid jio arguments")?
-                }
-            }
-            Some("tpl") => {
-                Self::Triple(Register::from_str(tokens.next().ok_or("Bad token get")?).unwrap())
+                Self::JumpIfOne(parse_register(tokens.next())?, parse_offset(tokens.next())?)
             }
+            Some("tpl") => Self::Triple(parse_register(tokens.next())?),
             Some(_) => Err("Unknown instruction")?,
         })
     }
@@ -149,7 +143,7 @@ impl Iterator for Machine {
                     self.instruction_ptr = (self.instruction_ptr as i32 + value) as usize
                 }
                 Instruction::JumpIfEven(reg, value) => {
-                    if self.get_register(reg) % 2 == 0 {
+                    if self.get_register(reg).is_multiple_of(2) {
                         self.instruction_ptr = (self.instruction_ptr as i32 + value) as usize;
                     } else {
                         self.instruction_ptr += 1;
@@ -174,3 +168,58 @@ impl Iterator for Machine {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn example() {
+        const INPUT: &str = "inc a\njio a, +2\ntpl a\ninc a";
+        let instructions = parse::lines(INPUT, Instruction::from_str).strict();
+        let mut machine = Machine::new((0, 0), instructions.unwrap());
+
+        for _ in machine.by_ref() {}
+
+        assert_eq!(machine.register_a, 2);
+    }
+
+    #[test]
+    fn malformed_input() {
+        assert!(Instruction::from_str("").is_err());
+        assert!(Instruction::from_str("hlf").is_err());
+        assert!(Instruction::from_str("inc c").is_err());
+        assert!(Instruction::from_str("jmp").is_err());
+        assert!(Instruction::from_str("jmp +x").is_err());
+        assert!(Instruction::from_str("jie a,").is_err());
+        assert!(Instruction::from_str("jio").is_err());
+        assert!(Instruction::from_str("nop a").is_err());
+    }
+
+    #[test]
+    fn skipped_lines() {
+        const INPUT: &str = "inc a\ninc c\ntpl a\njmp";
+
+        assert_eq!(Day23 {}.part_one(INPUT), Some("0".to_string()));
+        assert_eq!(
+            Day23 {}.diagnose(INPUT),
+            vec![
+                Error::at_line(2, "inc c", "Invalid register"),
+                Error::at_line(4, "jmp", "Missing offset"),
+            ]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn instruction_never_panics(s in "\\PC*") {
+            let _ = Instruction::from_str(&s);
+        }
+
+        #[test]
+        fn instruction_tokens_never_panic(s in "(hlf|inc|jmp|jie|jio|tpl)( [ab],?| [+-]?[0-9]{1,12}){0,3}") {
+            let _ = Instruction::from_str(&s);
+        }
+    }
+}
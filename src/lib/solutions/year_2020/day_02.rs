@@ -1,4 +1,5 @@
 use crate::utils::solution::Solution;
+use crate::Error;
 use std::{option::Option, str::FromStr};
 
 pub struct Day02 {}
@@ -58,15 +59,8 @@ impl PasswordRule {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-enum PasswordRuleParseError {
-    CharacterUnavailable,
-    MalformedRange,
-    ParseIntError,
-}
-
 impl FromStr for PasswordRule {
-    type Err = PasswordRuleParseError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let tokens: Vec<_> = s.split_ascii_whitespace().collect();
@@ -74,19 +68,10 @@ impl FromStr for PasswordRule {
 
         Ok(PasswordRule {
             values: (
-                bounds
-                    .next()
-                    .ok_or(PasswordRuleParseError::MalformedRange)?
-                    .map_err(|_| PasswordRuleParseError::ParseIntError)?,
-                bounds
-                    .next()
-                    .ok_or(PasswordRuleParseError::MalformedRange)?
-                    .map_err(|_| PasswordRuleParseError::ParseIntError)?,
+                bounds.next().ok_or("Malformed range")??,
+                bounds.next().ok_or("Malformed range")??,
             ),
-            letter: tokens[1]
-                .chars()
-                .next()
-                .ok_or(PasswordRuleParseError::CharacterUnavailable)?,
+            letter: tokens[1].chars().next().ok_or("Missing letter")?,
         })
     }
 }
@@ -115,17 +100,17 @@ mod tests {
     #[test]
     fn example_sled_validation() {
         let rules = rules();
-        assert_eq!(rules[0].sled_validate("abcde"), true);
-        assert_eq!(rules[1].sled_validate("cdefg"), false);
-        assert_eq!(rules[2].sled_validate("ccccccccc"), true);
+        assert!(rules[0].sled_validate("abcde"));
+        assert!(!rules[1].sled_validate("cdefg"));
+        assert!(rules[2].sled_validate("ccccccccc"));
     }
 
     #[test]
     fn example_toboggan_validation() {
         let rules = rules();
-        assert_eq!(rules[0].toboggan_validate("abcde"), true);
-        assert_eq!(rules[0].toboggan_validate("cdefg"), false);
-        assert_eq!(rules[0].toboggan_validate("ccccccccc"), false);
+        assert!(rules[0].toboggan_validate("abcde"));
+        assert!(!rules[0].toboggan_validate("cdefg"));
+        assert!(!rules[0].toboggan_validate("ccccccccc"));
     }
 
     #[test]
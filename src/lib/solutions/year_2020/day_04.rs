@@ -1,7 +1,8 @@
 use regex::Regex;
-use std::{collections::HashMap, num::ParseIntError, str::FromStr};
+use std::{collections::HashMap, str::FromStr};
 
 use crate::utils::solution::Solution;
+use crate::Error;
 
 pub struct Day04 {}
 
@@ -22,18 +23,6 @@ fn check_passports(input: &str, checker: fn(&Passport) -> bool) -> usize {
         .count()
 }
 
-#[derive(Clone, Debug)]
-enum Day04Error {
-    ParseIntError,
-    UnknownField,
-}
-
-impl From<std::num::ParseIntError> for Day04Error {
-    fn from(_err: ParseIntError) -> Day04Error {
-        Day04Error::ParseIntError
-    }
-}
-
 #[derive(Clone, Copy, Debug)]
 enum Height {
     Metric(i32),
@@ -42,10 +31,12 @@ enum Height {
 }
 
 impl FromStr for Height {
-    type Err = Day04Error;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (i, _) = s.char_indices().rev().nth(1).unwrap();
+        let Some((i, _)) = s.char_indices().rev().nth(1) else {
+            return Ok(Height::Unknown);
+        };
         Ok(match &s[i..] {
             "cm" => Height::Metric(s[..i].parse::<i32>()?),
             "in" => Height::Imperial(s[..i].parse::<i32>()?),
@@ -67,7 +58,7 @@ enum EyeColor {
 }
 
 impl FromStr for EyeColor {
-    type Err = Day04Error;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
@@ -128,13 +119,13 @@ impl Passport {
 }
 
 impl FromStr for Passport {
-    type Err = Day04Error;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut fields = HashMap::new();
         for token in s.split_whitespace() {
-            let mut x = token.split(':');
-            fields.insert(x.next().unwrap(), x.next().unwrap());
+            let (key, value) = token.split_once(':').ok_or("Missing field value")?;
+            fields.insert(key, value);
         }
 
         let mut passport = Passport::default();
@@ -148,10 +139,43 @@ impl FromStr for Passport {
                 "ecl" => passport.eye_color = value.parse().ok(),
                 "pid" => passport.passport_id = Some(value.to_string()),
                 "cid" => passport.country_id = Some(value.to_string()),
-                _ => return Err(Day04Error::UnknownField),
+                _ => return Err(Error::InvalidInput("Unknown passport field")),
             }
         }
 
         Ok(passport)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn malformed_input() {
+        assert!(Passport::from_str("byr").is_err());
+        assert!(Passport::from_str("byr:19x0").is_err());
+        assert!(Passport::from_str("abc:123").is_err());
+        assert!(Passport::from_str("hgt:xxcm").is_err());
+        assert!(matches!(Height::from_str("5"), Ok(Height::Unknown)));
+        assert!(matches!(Height::from_str(""), Ok(Height::Unknown)));
+    }
+
+    proptest! {
+        #[test]
+        fn passport_never_panics(s in "\\PC*") {
+            let _ = Passport::from_str(&s);
+        }
+
+        #[test]
+        fn passport_fields_never_panic(s in "((byr|iyr|eyr|hgt|hcl|ecl|pid|cid)(:\\PC{0,6})?[ \n]){0,8}") {
+            let _ = Passport::from_str(&s);
+        }
+
+        #[test]
+        fn height_never_panics(s in "\\PC{0,3}(cm|in)?") {
+            let _ = Height::from_str(&s);
+        }
+    }
+}
@@ -1,14 +1,10 @@
 use crate::utils::solution::Solution;
+use crate::Error;
 use itertools::Itertools;
 use std::collections::HashSet;
 
 pub struct Day05 {}
 
-#[derive(Clone, Copy, Debug)]
-enum Day05Error {
-    WalkNotConverged,
-}
-
 impl Solution for Day05 {
     fn part_one(&self, input: &str) -> Option<String> {
         Some(input.lines().map(seat_id).max().unwrap().to_string())
@@ -57,7 +53,7 @@ impl Bounds {
     }
 }
 
-fn walk(s: &[char], mut bounds: Bounds) -> Result<i32, Day05Error> {
+fn walk(s: &[char], mut bounds: Bounds) -> Result<i32, Error> {
     for c in s {
         match c {
             'F' | 'L' => bounds.upper = bounds.mid(),
@@ -69,7 +65,10 @@ fn walk(s: &[char], mut bounds: Bounds) -> Result<i32, Day05Error> {
     if bounds.lower == bounds.upper {
         Ok(bounds.lower)
     } else {
-        Err(Day05Error::WalkNotConverged)
+        Err(Error::Solver(format!(
+            "walk stopped between {} and {}",
+            bounds.lower, bounds.upper
+        )))
     }
 }
 
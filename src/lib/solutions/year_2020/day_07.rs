@@ -1,62 +1,91 @@
+use crate::utils::graph::{traversal, Edge, Graph};
 use crate::utils::solution::Solution;
 use itertools::Itertools;
 use std::collections::HashMap;
 
-type Luggage = HashMap<String, Vec<(i32, String)>>;
+/// Bag rules as edges from each container to the bags it holds directly,
+/// weighted by how many it holds
+type Luggage = Graph<String, usize>;
+
+const TARGET: &str = "shiny gold";
 
 pub struct Day07 {}
 
 impl Solution for Day07 {
     fn part_one(&self, input: &str) -> Option<String> {
-        let luggage: Luggage = input.lines().map(parse_contents).collect();
-        let mut count = 0;
-
-        'bags: for children in luggage.values() {
-            let mut to_check = vec![children];
-            while !to_check.is_empty() {
-                for (_, child) in to_check.pop().unwrap() {
-                    if *child == *"shiny gold" {
-                        count += 1;
-                        continue 'bags;
-                    } else {
-                        to_check.push(&luggage[child]);
-                    }
-                }
-            }
-        }
-
-        Some(count.to_string())
+        let luggage = parse_luggage(input);
+        let containers = traversal::reachable(TARGET.to_string(), |bag| {
+            luggage.predecessors(bag).cloned().collect::<Vec<_>>()
+        });
+
+        Some((containers.len() - 1).to_string())
     }
 
     fn part_two(&self, input: &str) -> Option<String> {
-        let luggage: Luggage = input.lines().map(parse_contents).collect();
-        Some((luggage.count_bags("shiny gold") - 1).to_string())
+        let luggage = parse_luggage(input);
+        Some((count_bags(&luggage, TARGET)? - 1).to_string())
     }
 }
 
-trait ContainsBags {
-    fn count_bags(&self, target: &str) -> usize;
-}
-
-impl ContainsBags for Luggage {
-    fn count_bags(&self, target: &str) -> usize {
-        let children = &self[target];
+/// Counts `target` along with every bag nested inside it, totalling the
+/// innermost bags first
+fn count_bags(luggage: &Luggage, target: &str) -> Option<usize> {
+    let order = luggage.topological_sort().ok()?;
+    let mut totals: HashMap<&str, usize> = HashMap::new();
+    for bag in order.iter().rev() {
+        let nested = luggage
+            .out_edges(bag)
+            .map(|edge| edge.data * totals[edge.end.as_str()])
+            .sum::<usize>();
 
-        1 + children
-            .iter()
-            .map(|(num, bag)| (*num as usize) * self.count_bags(bag))
-            .sum::<usize>()
+        totals.insert(bag, 1 + nested);
     }
+
+    Some(totals.get(target).copied().unwrap_or(1))
+}
+
+fn parse_luggage(input: &str) -> Luggage {
+    input
+        .lines()
+        .flat_map(parse_contents)
+        .collect::<Vec<_>>()
+        .into()
 }
 
-fn parse_contents(s: &str) -> (String, Vec<(i32, String)>) {
+fn parse_contents(s: &str) -> Vec<Edge<String, usize>> {
     let mut halves = s.split(" bags contain ");
     let (left, right) = (halves.next().unwrap(), halves.next().unwrap());
 
-    let mut contents = Vec::new();
-    for (qty, adj, color, _) in right.split_whitespace().tuples() {
-        contents.push((qty.parse::<i32>().unwrap(), format!("{} {}", adj, color)));
-    }
+    right
+        .split_whitespace()
+        .tuples()
+        .map(|(qty, adj, color, _)| {
+            Edge::with_data(
+                left.to_string(),
+                format!("{} {}", adj, color),
+                qty.parse::<usize>().unwrap(),
+            )
+        })
+        .collect()
+}
 
-    (left.to_string(), contents)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.";
+
+    #[test]
+    fn example() {
+        assert_eq!(Day07 {}.part_one(INPUT), Some("4".to_string()));
+        assert_eq!(Day07 {}.part_two(INPUT), Some("32".to_string()));
+    }
 }
@@ -1,4 +1,5 @@
 use crate::utils::solution::Solution;
+use crate::Error;
 use itertools::Itertools;
 
 pub struct Day08 {}
@@ -38,12 +39,6 @@ fn parse_input(input: &str) -> Tape {
 
 type Tape = Vec<Instruction>;
 
-#[derive(Clone, Copy, Debug)]
-enum Day08Error {
-    BadCommand,
-    UnknownCommand,
-}
-
 #[derive(Clone, Copy, Debug, PartialEq)]
 enum Instruction {
     Acc(i32),
@@ -52,21 +47,21 @@ enum Instruction {
 }
 
 impl std::str::FromStr for Instruction {
-    type Err = Day08Error;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let (cmd, arg) = s
             .split_whitespace()
             .collect_tuple()
-            .ok_or(Day08Error::BadCommand)?;
+            .ok_or("Expected `<command> <argument>`")?;
 
-        let val = arg.parse().unwrap();
+        let val = arg.parse()?;
 
         match cmd {
             "acc" => Ok(Instruction::Acc(val)),
             "jmp" => Ok(Instruction::Jmp(val)),
             "nop" => Ok(Instruction::Nop(val)),
-            _ => Err(Day08Error::UnknownCommand),
+            _ => Err(Error::InvalidInput("Unknown command")),
         }
     }
 }
@@ -184,4 +179,16 @@ mod tests {
             assert_eq!(input.parse::<Instruction>().unwrap(), instruction);
         }
     }
+
+    #[test]
+    fn test_instruction_errors() {
+        assert_eq!(
+            "hop +1".parse::<Instruction>(),
+            Err(Error::InvalidInput("Unknown command"))
+        );
+        assert!(matches!(
+            "jmp +x".parse::<Instruction>(),
+            Err(Error::ParseInt(_))
+        ));
+    }
 }
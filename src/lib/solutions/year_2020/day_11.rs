@@ -1,6 +1,6 @@
+use crate::utils::automaton::{Automaton, Rule};
+use crate::utils::grid::{self, Connectivity, Grid, Location};
 use crate::utils::solution::*;
-use itertools::Itertools;
-use std::str::FromStr;
 
 pub struct Day11 {}
 
@@ -15,17 +15,23 @@ impl Solution for Day11 {
 }
 
 fn run_simulation(input: &str, neighbor_threshold: i32, neighbor_radius: Option<i32>) -> i32 {
-    let mut sim = GridSimulation::new(input, neighbor_threshold);
+    let seats = Grid::from_str_with(input, GridState::try_from).unwrap();
+    let mut sim = Automaton::new(
+        seats,
+        SeatingRule {
+            neighbor_threshold,
+            neighbor_radius,
+        },
+    );
 
-    while sim.state != SimulationState::Finished {
-        sim.step(neighbor_radius);
-    }
+    sim.run_to_fixed_point();
 
-    sim.num_occupied()
+    sim.cells()
+        .iter()
+        .filter(|&&state| state == GridState::Occupied)
+        .count() as i32
 }
 
-type Grid = Vec<Vec<GridState>>;
-
 #[derive(Clone, Copy, Debug, PartialEq)]
 enum GridState {
     Empty,
@@ -33,134 +39,79 @@ enum GridState {
     Floor,
 }
 
-impl FromStr for GridState {
-    type Err = super::AdventError;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "L" => Ok(GridState::Empty),
-            "#" => Ok(GridState::Occupied),
-            "." => Ok(GridState::Floor),
-            _ => Err(super::AdventError::BadInput),
+impl TryFrom<char> for GridState {
+    type Error = grid::error::Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(GridState::Empty),
+            '#' => Ok(GridState::Occupied),
+            '.' => Ok(GridState::Floor),
+            _ => Err(Self::Error::UnexpectedChar(value)),
         }
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
-enum SimulationState {
-    NotStarted,
-    Running,
-    Finished,
-}
-
-#[derive(Clone)]
-struct GridSimulation {
-    grid: Grid,
-    bounds: (i32, i32),
-    state: SimulationState,
+/// Seats empty once at least `neighbor_threshold` occupied seats are visible
+/// within `neighbor_radius` (unlimited if `None`) and fill when none are.
+#[derive(Clone, Copy, Debug)]
+struct SeatingRule {
     neighbor_threshold: i32,
+    neighbor_radius: Option<i32>,
 }
 
-impl GridSimulation {
-    fn new(s: &str, neighbor_threshold: i32) -> Self {
-        let g: Grid = s
-            .lines()
-            .map(|line| {
-                line.chars()
-                    .map(|c| c.to_string().parse::<GridState>().unwrap())
-                    .collect()
-            })
-            .collect();
+impl SeatingRule {
+    fn num_occupied_neighbors(&self, grid: &Grid<GridState>, location: Location) -> i32 {
+        let max_distance = self.neighbor_radius.map_or(usize::MAX, |d| d as usize);
 
-        GridSimulation {
-            bounds: (g.len() as i32, g[0].len() as i32),
-            grid: g,
-            state: SimulationState::NotStarted,
-            neighbor_threshold,
-        }
-    }
-
-    fn step(&mut self, neighbor_radius: Option<i32>) {
-        let mut tmp = self.grid.clone();
-
-        let mut continue_sim = false;
-        for (row, col) in (0..self.bounds.0).cartesian_product(0..self.bounds.1) {
-            match self.grid[row as usize][col as usize] {
-                GridState::Floor => {}
-                GridState::Empty => {
-                    if let 0 = self.num_occupied_neighbors((row, col), neighbor_radius) {
-                        tmp[row as usize][col as usize] = GridState::Occupied;
-                        continue_sim = true;
-                    }
-                }
-                GridState::Occupied => {
-                    match self.num_occupied_neighbors((row, col), neighbor_radius) {
-                        n if (n >= self.neighbor_threshold) => {
-                            tmp[row as usize][col as usize] = GridState::Empty;
-                            continue_sim = true;
-                        }
-                        _ => {}
-                    }
-                }
-            }
-        }
-
-        self.state = match continue_sim {
-            true => {
-                self.grid = tmp;
-                SimulationState::Running
-            }
-            false => SimulationState::Finished,
-        };
+        Connectivity::Moore
+            .offsets()
+            .iter()
+            .filter(|&&direction| {
+                grid.ray(location, direction)
+                    .take(max_distance)
+                    .map(|neighbor| grid[neighbor])
+                    .find(|&state| state != GridState::Floor)
+                    == Some(GridState::Occupied)
+            })
+            .count() as i32
     }
+}
 
-    fn num_occupied_neighbors(&self, (row, col): (i32, i32), max_distance: Option<i32>) -> i32 {
-        let mut neighbors = 0;
-
-        let steps = [-1, 0, 1];
-        for (dr, dc) in steps.iter().cartesian_product(steps.iter()) {
-            if dr == &0 && dc == &0 {
-                continue;
-            }
-
-            let mut radius = 1;
-
-            while max_distance.map(|d| radius <= d).unwrap_or(true) {
-                let neighbor_row = row + radius * dr;
-                if neighbor_row < 0 || neighbor_row >= self.bounds.0 {
-                    break;
-                }
-
-                let neighbor_col = col + radius * dc;
-                if neighbor_col < 0 || neighbor_col >= self.bounds.1 {
-                    break;
-                }
-
-                match self.grid[neighbor_row as usize][neighbor_col as usize] {
-                    GridState::Floor => {}
-                    GridState::Empty => {
-                        break;
-                    }
-                    GridState::Occupied => {
-                        neighbors += 1;
-                        break;
-                    }
-                }
-
-                radius += 1;
-            }
+impl Rule<Grid<GridState>> for SeatingRule {
+    fn next_state(&self, grid: &Grid<GridState>, location: Location) -> GridState {
+        match grid[location] {
+            GridState::Floor => GridState::Floor,
+            GridState::Empty => match self.num_occupied_neighbors(grid, location) {
+                0 => GridState::Occupied,
+                _ => GridState::Empty,
+            },
+            GridState::Occupied => match self.num_occupied_neighbors(grid, location) {
+                n if n >= self.neighbor_threshold => GridState::Empty,
+                _ => GridState::Occupied,
+            },
         }
-
-        neighbors
     }
+}
 
-    fn num_occupied(&self) -> i32 {
-        self.grid
-            .iter()
-            .flatten()
-            .map(|s| match s {
-                GridState::Occupied => 1,
-                _ => 0,
-            })
-            .sum()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL";
+
+    #[test]
+    fn example() {
+        assert_eq!(run_simulation(INPUT, 4, Some(1)), 37);
+        assert_eq!(run_simulation(INPUT, 5, None), 26);
     }
 }
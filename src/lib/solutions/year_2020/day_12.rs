@@ -1,4 +1,5 @@
 use crate::utils::solution::*;
+use crate::Error;
 use std::str::FromStr;
 
 pub struct Day12 {}
@@ -63,11 +64,11 @@ enum Maneuver {
 }
 
 impl FromStr for Maneuver {
-    type Err = super::AdventError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut chars = s.chars();
-        let direction = chars.next().ok_or(super::AdventError::BadInput)?;
+        let direction = chars.next().ok_or("Missing maneuver")?;
         let value = chars.as_str().parse()?;
         match direction {
             'N' => Ok(Self::Move(Heading::North, value)),
@@ -77,7 +78,7 @@ impl FromStr for Maneuver {
             'L' => Ok(Self::Rotate(value / 90)),
             'R' => Ok(Self::Rotate(-value / 90)),
             'F' => Ok(Self::Forward(value)),
-            _ => Err(super::AdventError::BadInput),
+            _ => Err(Error::InvalidInput("Unknown maneuver")),
         }
     }
 }
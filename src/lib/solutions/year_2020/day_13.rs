@@ -1,4 +1,5 @@
 use crate::utils::solution::*;
+use crate::Error;
 pub struct Day13 {}
 
 impl Solution for Day13 {
@@ -24,13 +25,13 @@ impl Solution for Day13 {
     }
 }
 
-fn parse_input(input: &str) -> Result<(i32, Vec<Option<i32>>), super::AdventError> {
+fn parse_input(input: &str) -> Result<(i32, Vec<Option<i32>>), Error> {
     let mut lines = input.lines();
     Ok((
-        lines.next().ok_or(super::AdventError::BadInput)?.parse()?,
+        lines.next().ok_or("Missing departure time")?.parse()?,
         lines
             .next()
-            .ok_or(super::AdventError::BadInput)?
+            .ok_or("Missing bus schedule")?
             .split(',')
             .map(|bus| bus.parse().ok())
             .collect(),
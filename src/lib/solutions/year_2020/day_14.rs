@@ -1,4 +1,5 @@
 use crate::utils::solution::Solution;
+use crate::Error;
 use itertools::Itertools;
 use std::{collections::HashMap, str::FromStr};
 
@@ -81,7 +82,7 @@ impl Mask {
 }
 
 impl FromStr for Mask {
-    type Err = super::AdventError;
+    type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         const CHARS: [char; 3] = ['0', '1', 'X'];
         let mut mask = Mask::new();
@@ -105,7 +106,7 @@ enum Command {
 }
 
 impl FromStr for Command {
-    type Err = super::AdventError;
+    type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.starts_with("mask") {
             let trigits = s.split(" = ").last().unwrap();
@@ -1,4 +1,6 @@
+use crate::utils::matching::{self, Candidates};
 use crate::utils::solution::Solution;
+use crate::Error;
 use std::{
     collections::{HashMap, HashSet},
     str::FromStr,
@@ -34,7 +36,7 @@ impl Solution for Day16 {
             .iter()
             .all(|ticket| ticket.values.len() == tickets[0].values.len()));
 
-        let fields = associated_fields(&mut potential_fields(&tickets, &constraints));
+        let fields = matching::propagate(potential_fields(&tickets, &constraints)).ok()?;
 
         Some(
             fields
@@ -47,10 +49,8 @@ impl Solution for Day16 {
     }
 }
 
-fn potential_fields(
-    tickets: &[Ticket],
-    constraints: &[Constraint],
-) -> Vec<(String, HashSet<usize>)> {
+/// Positions on every ticket whose values satisfy each constraint
+fn potential_fields(tickets: &[Ticket], constraints: &[Constraint]) -> Candidates<String, usize> {
     let mut valid_fields = HashMap::new();
     for field_idx in 0..tickets[0].values.len() {
         for constraint in constraints {
@@ -66,36 +66,15 @@ fn potential_fields(
         }
     }
 
-    valid_fields.into_iter().collect()
+    valid_fields
 }
 
-fn associated_fields(fields: &mut [(String, HashSet<usize>)]) -> HashMap<String, usize> {
-    fields.sort_by(|(_, first), (_, second)| second.len().cmp(&first.len()));
-
-    let n = fields.len();
-
-    let mut deduced_fields = HashMap::new();
-    for i in 0..(n - 1) {
-        deduced_fields.insert(
-            fields[i].0.clone(),
-            *fields[i].1.difference(&fields[i + 1].1).next().unwrap(),
-        );
-    }
-
-    deduced_fields.insert(
-        fields[n - 1].0.clone(),
-        fields[n - 1].1.drain().next().unwrap(),
-    );
-
-    deduced_fields
-}
-
-fn parse_input(input: &str) -> Result<(Vec<Constraint>, Ticket, Vec<Ticket>), super::AdventError> {
+fn parse_input(input: &str) -> Result<(Vec<Constraint>, Ticket, Vec<Ticket>), Error> {
     let mut parts = input.split("\n\n");
 
     let constraints = parts
         .next()
-        .ok_or(super::AdventError::BadInput)?
+        .ok_or("Missing constraints")?
         .trim()
         .lines()
         .filter_map(|line| line.parse().ok())
@@ -103,7 +82,7 @@ fn parse_input(input: &str) -> Result<(Vec<Constraint>, Ticket, Vec<Ticket>), su
 
     let my_ticket = parts
         .next()
-        .ok_or(super::AdventError::BadInput)?
+        .ok_or("Missing your ticket")?
         .trim()
         .lines()
         .last()
@@ -112,7 +91,7 @@ fn parse_input(input: &str) -> Result<(Vec<Constraint>, Ticket, Vec<Ticket>), su
 
     let tickets = parts
         .next()
-        .ok_or(super::AdventError::BadInput)?
+        .ok_or("Missing nearby tickets")?
         .trim()
         .lines()
         .skip(1) // Skip "nearby tickets: "
@@ -161,7 +140,7 @@ impl Ticket {
 }
 
 impl FromStr for Ticket {
-    type Err = super::AdventError;
+    type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Ticket {
             values: s.split(',').filter_map(|v| v.parse().ok()).collect(),
@@ -182,12 +161,12 @@ impl Range {
 }
 
 impl FromStr for Range {
-    type Err = super::AdventError;
+    type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut parts = s.split('-');
         Ok(Range {
-            low: parts.next().ok_or(super::AdventError::BadInput)?.parse()?,
-            high: parts.next().ok_or(super::AdventError::BadInput)?.parse()?,
+            low: parts.next().ok_or("Malformed range")?.parse()?,
+            high: parts.next().ok_or("Malformed range")?.parse()?,
         })
     }
 }
@@ -205,16 +184,13 @@ impl Constraint {
 }
 
 impl FromStr for Constraint {
-    type Err = super::AdventError;
+    type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut parts = s.split(": ");
-        let name = parts
-            .next()
-            .ok_or(super::AdventError::BadInput)?
-            .to_string();
+        let name = parts.next().ok_or("Missing constraint name")?.to_string();
         let mut constraint_specs = parts
             .next()
-            .ok_or(super::AdventError::BadInput)?
+            .ok_or("Missing constraint ranges")?
             .split(" or ");
 
         Ok(Constraint {
@@ -222,13 +198,40 @@ impl FromStr for Constraint {
             ranges: (
                 constraint_specs
                     .next()
-                    .ok_or(super::AdventError::BadInput)?
+                    .ok_or("Missing constraint range")?
                     .parse()?,
                 constraint_specs
                     .next()
-                    .ok_or(super::AdventError::BadInput)?
+                    .ok_or("Missing constraint range")?
                     .parse()?,
             ),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_positions() {
+        const INPUT: &str = "class: 0-1 or 4-19
+row: 0-5 or 8-19
+seat: 0-13 or 16-19
+
+your ticket:
+11,12,13
+
+nearby tickets:
+3,9,18
+15,1,5
+5,14,9";
+
+        let (constraints, _, tickets) = parse_input(INPUT).unwrap();
+        let fields = matching::propagate(potential_fields(&tickets, &constraints)).unwrap();
+
+        assert_eq!(fields["row"], 0);
+        assert_eq!(fields["class"], 1);
+        assert_eq!(fields["seat"], 2);
+    }
+}
@@ -1,80 +1,38 @@
+use crate::utils::automaton::{Automaton, Lattice};
+use crate::utils::grid::SparseGrid;
 use crate::utils::solution::Solution;
-use itertools::iproduct;
-use std::{collections::HashSet, ops::RangeInclusive};
+use glam::{IVec3, IVec4};
 
 pub struct Day17 {}
 
 impl Solution for Day17 {
     fn part_one(&self, input: &str) -> Option<String> {
-        Some(simulate(parse_input(input), Dimensions::Three).to_string())
+        Some(simulate(parse_input(input, |x, y| IVec3::new(x, y, 0))).to_string())
     }
 
     fn part_two(&self, input: &str) -> Option<String> {
-        Some(simulate(parse_input(input), Dimensions::Four).to_string())
+        Some(simulate(parse_input(input, |x, y| IVec4::new(x, y, 0, 0))).to_string())
     }
 }
 
-fn simulate(mut active_sites: HashSet<Coordinate>, dimensions: Dimensions) -> usize {
-    for _ in 0..6 {
-        active_sites = evolve(&active_sites, dimensions);
-    }
-
-    active_sites.len()
-}
-
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
-struct Coordinate {
-    w: i32,
-    x: i32,
-    y: i32,
-    z: i32,
+fn simulate<P: Lattice>(active_sites: SparseGrid<(), P>) -> usize {
+    Automaton::new(active_sites, conway_cubes)
+        .nth(5)
+        .map_or(0, |cubes| cubes.len())
 }
 
-#[derive(Clone, Copy, Debug)]
-enum Dimensions {
-    Three,
-    Four,
-}
-
-impl Coordinate {
-    fn new(w: i32, x: i32, y: i32, z: i32) -> Self {
-        Coordinate { w, x, y, z }
-    }
-
-    fn neighbors(&self, dimensions: Dimensions) -> impl Iterator<Item = Coordinate> + '_ {
-        match dimensions {
-            Dimensions::Three => self.neighbors_3d(),
-            Dimensions::Four => self.neighbors_4d(),
-        }
-    }
-
-    fn neighbors_3d(&self) -> Box<dyn Iterator<Item = Coordinate> + '_> {
-        const DELTAS: RangeInclusive<i32> = -1..=1;
-        Box::new(
-            iproduct!(DELTAS.clone(), DELTAS.clone(), DELTAS.clone())
-                .filter(|(dx, dy, dz)| !((*dx == 0) && (*dy == 0) && (*dz == 0)))
-                .map(move |(dx, dy, dz)| Coordinate::new(0, self.x + dx, self.y + dy, self.z + dz)),
-        )
-    }
-
-    fn neighbors_4d(&self) -> Box<dyn Iterator<Item = Coordinate> + '_> {
-        const DELTAS: RangeInclusive<i32> = -1..=1;
-        Box::new(
-            iproduct!(
-                DELTAS.clone(),
-                DELTAS.clone(),
-                DELTAS.clone(),
-                DELTAS.clone()
-            )
-            .filter(|(dw, dx, dy, dz)| !((*dw == 0) && (*dx == 0) && (*dy == 0) && (*dz == 0)))
-            .map(move |(dw, dx, dy, dz)| {
-                Coordinate::new(self.w + dw, self.x + dx, self.y + dy, self.z + dz)
-            }),
-        )
-    }
+fn conway_cubes<P: Lattice>(cubes: &SparseGrid<(), P>, position: P) -> bool {
+    let active = position
+        .moore_neighbors()
+        .into_iter()
+        .filter(|&neighbor| cubes.contains(neighbor))
+        .count();
+    matches!((cubes.contains(position), active), (true, 2) | (_, 3))
 }
 
-fn parse_input(input: &str) -> HashSet<Coordinate> {
+/// Places the active (`'#'`) sites of the initial slice into a lattice of
+/// any dimension using `embed(x, y)`
+fn parse_input<P: Lattice>(input: &str, embed: fn(i32, i32) -> P) -> SparseGrid<(), P> {
     input
         .lines()
         .enumerate()
@@ -82,40 +40,20 @@ fn parse_input(input: &str) -> HashSet<Coordinate> {
             line.chars()
                 .enumerate()
                 .filter(move |(_, ch)| *ch == '#')
-                .map(move |(y, _)| Coordinate::new(0_i32, x as i32, y as i32, 0_i32))
+                .map(move |(y, _)| embed(x as i32, y as i32))
         })
         .collect()
 }
 
-fn evolve(cubes: &HashSet<Coordinate>, dimensions: Dimensions) -> HashSet<Coordinate> {
-    let mut next = HashSet::new();
-    for active_cube in cubes {
-        let num_active_neighbors = active_cube
-            .neighbors(dimensions)
-            .map(|neighbor| match cubes.contains(&neighbor) {
-                true => 1,
-                false => 0,
-            })
-            .sum::<i32>();
-
-        if num_active_neighbors == 2 || num_active_neighbors == 3 {
-            next.insert(*active_cube);
-        }
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    for neighbor in cubes.iter().flat_map(|coord| coord.neighbors(dimensions)) {
-        let num_active_neighbors = neighbor
-            .neighbors(dimensions)
-            .map(|neighbor| match cubes.get(&neighbor) {
-                Some(_) => 1,
-                None => 0,
-            })
-            .sum::<i32>();
+    const INPUT: &str = ".#.\n..#\n###";
 
-        if num_active_neighbors == 3 {
-            next.insert(neighbor);
-        }
+    #[test]
+    fn example() {
+        assert_eq!(Day17 {}.part_one(INPUT), Some("112".to_string()));
+        assert_eq!(Day17 {}.part_two(INPUT), Some("848".to_string()));
     }
-
-    next
 }
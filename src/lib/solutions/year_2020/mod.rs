@@ -1,5 +1,3 @@
-use std::{char::ParseCharError, num::ParseIntError};
-
 pub mod day_01;
 pub mod day_02;
 pub mod day_03;
@@ -18,20 +16,3 @@ pub mod day_15;
 pub mod day_16;
 pub mod day_17;
 pub mod day_18;
-
-#[derive(Clone, Copy, Debug)]
-pub enum AdventError {
-    BadInput,
-}
-
-impl From<ParseCharError> for AdventError {
-    fn from(_: ParseCharError) -> Self {
-        Self::BadInput
-    }
-}
-
-impl From<ParseIntError> for AdventError {
-    fn from(_: ParseIntError) -> Self {
-        Self::BadInput
-    }
-}
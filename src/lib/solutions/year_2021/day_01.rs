@@ -1,4 +1,6 @@
+use crate::utils::parse;
 use crate::utils::solution::Solution;
+use crate::Error;
 
 pub struct Day01 {}
 
@@ -10,10 +12,15 @@ impl Solution for Day01 {
     fn part_two(&self, input: &str) -> Option<String> {
         Some(parse_and_evaluate(input, 3).to_string())
     }
+
+    fn diagnose(&self, input: &str) -> Vec<Error> {
+        parse::numbers::<i32>(input).errors
+    }
 }
 
 fn parse_and_evaluate(input: &str, skip_size: usize) -> usize {
-    get_positive_deltas::<_, i32>(input.lines().map(|l| l.parse().unwrap()), skip_size)
+    let depths = parse::numbers::<i32>(input).values;
+    get_positive_deltas(depths.into_iter(), skip_size)
 }
 
 fn get_positive_deltas<T, U>(xs: T, skip_size: usize) -> usize
@@ -1,4 +1,5 @@
 use crate::utils::solution::Solution;
+use crate::Error;
 use std::str::FromStr;
 
 pub struct Day02 {}
@@ -80,7 +81,7 @@ enum SubCommand {
 }
 
 impl std::str::FromStr for SubCommand {
-    type Err = Box<dyn std::error::Error>;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if let Some((a, b)) = s.split_once(' ') {
@@ -88,10 +89,10 @@ impl std::str::FromStr for SubCommand {
                 "forward" => Self::Forward(b.parse()?),
                 "down" => Self::Down(b.parse()?),
                 "up" => Self::Up(b.parse()?),
-                _ => return Err(format!("Invalid token ({})", a).into()),
+                _ => Err("Invalid command")?,
             })
         } else {
-            Err(String::from("Could not split string").into())
+            Err(Error::InvalidInput("Could not split string"))
         }
     }
 }
@@ -1,4 +1,5 @@
 use crate::utils::solution::Solution;
+use crate::Error;
 use std::{fmt::Display, str::FromStr};
 
 pub struct Day04 {}
@@ -113,7 +114,7 @@ impl Board {
 }
 
 impl FromStr for Board {
-    type Err = Box<dyn std::error::Error>;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut board = Board::default();
@@ -1,6 +1,8 @@
-use itertools::Itertools;
-use std::str::FromStr;
+use crate::utils::grid::SparseGrid;
 use crate::utils::solution::Solution;
+use crate::Error;
+use glam::IVec2;
+use std::str::FromStr;
 
 pub struct Day05 {}
 
@@ -15,13 +17,19 @@ impl Solution for Day05 {
 }
 
 fn vent_counts(s: &str, filter_fn: fn(&Segment) -> bool) -> usize {
-    s.lines()
+    let mut floor = SparseGrid::new();
+    for vent in s
+        .lines()
         .flat_map(|line| line.parse::<Segment>())
         .filter(filter_fn)
         .flat_map(|segment| segment.vents())
-        .counts()
-        .into_iter()
-        .filter(|(_, num_occurrences)| *num_occurrences > 1)
+    {
+        *floor.get_or_insert_with(IVec2::new(vent.x, vent.y), || 0) += 1;
+    }
+
+    floor
+        .iter()
+        .filter(|(_, &num_occurrences)| num_occurrences > 1)
         .count()
 }
 
@@ -32,12 +40,10 @@ struct Point {
 }
 
 impl FromStr for Point {
-    type Err = Box<dyn std::error::Error>;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (x_str, y_str) = s
-            .split_once(',')
-            .ok_or(format!("Malformed coordinate: {}", s))?;
+        let (x_str, y_str) = s.split_once(',').ok_or("Malformed coordinate")?;
 
         Ok(Point {
             x: x_str.parse()?,
@@ -75,12 +81,10 @@ impl Segment {
 }
 
 impl FromStr for Segment {
-    type Err = Box<dyn std::error::Error>;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (start_str, end_str) = s
-            .split_once(" -> ")
-            .ok_or(format!("Malformed segment string: {}", s))?;
+        let (start_str, end_str) = s.split_once(" -> ").ok_or("Malformed segment string")?;
 
         Ok(Self {
             start: start_str.parse()?,
@@ -1,5 +1,6 @@
-use std::str::FromStr;
 use crate::utils::solution::Solution;
+use crate::Error;
+use std::str::FromStr;
 
 pub struct Day06 {}
 
@@ -43,7 +44,7 @@ impl FishSimulator {
 }
 
 impl FromStr for FishSimulator {
-    type Err = Box<dyn std::error::Error>;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let digits = s.split(',').map(|s| s.trim().parse::<i32>());
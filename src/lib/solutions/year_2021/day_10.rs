@@ -1,5 +1,6 @@
-use std::str::FromStr;
 use crate::utils::solution::Solution;
+use crate::Error;
+use std::str::FromStr;
 
 pub struct Day10 {}
 
@@ -81,7 +82,7 @@ impl Symbol {
 }
 
 impl TryFrom<char> for Symbol {
-    type Error = Box<dyn std::error::Error>;
+    type Error = Error;
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
         match ['(', '[', '{', '<'].contains(&value) {
@@ -97,7 +98,7 @@ impl TryFrom<char> for Symbol {
                 ']' => Grouping::Bracket,
                 '}' => Grouping::Brace,
                 '>' => Grouping::Angle,
-                _ => return Err(format!("Invalid token: {}", value).into()),
+                _ => Err("Invalid token")?,
             })),
         }
     }
@@ -122,10 +123,13 @@ enum ChunkStatus {
 }
 
 impl FromStr for ChunkStatus {
-    type Err = Box<dyn std::error::Error>;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let symbols = s.chars().map(|ch| Symbol::try_from(ch).unwrap());
+        let symbols = s
+            .chars()
+            .map(Symbol::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
         let mut stack: Vec<Symbol> = Vec::default();
 
         for symbol in symbols {
@@ -1,6 +1,7 @@
-use itertools::Itertools;
-use std::str::FromStr;
+use crate::utils::automaton::Automaton;
+use crate::utils::grid::{self, Boundary, Connectivity, Grid, Location};
 use crate::utils::solution::Solution;
+use std::str::FromStr;
 
 pub struct Day11 {}
 
@@ -30,91 +31,58 @@ impl Solution for Day11 {
 
 #[derive(Clone)]
 struct OctopusGarden {
-    data: Vec<i32>,
-    num_rows: usize,
-    num_cols: usize,
+    data: Grid<i32>,
     flash_count: i32,
 }
 
-impl OctopusGarden {
-    fn coord_to_idx(&self, (r, c): (usize, usize)) -> usize {
-        r * self.num_cols + c
-    }
-
-    fn idx_to_coord(&self, idx: usize) -> (usize, usize) {
-        (idx / self.num_cols, idx % self.num_cols)
-    }
-
-    fn cardinal_neighbors(&self, idx: usize) -> impl Iterator<Item = usize> + '_ + Clone {
-        let (r, c) = self.idx_to_coord(idx);
-
-        [
-            r.gt(&0).then_some((r - 1, c)),
-            c.gt(&0).then_some((r, c - 1)),
-            c.lt(&(self.num_cols - 1)).then_some((r, c + 1)),
-            r.lt(&(self.num_rows - 1)).then_some((r + 1, c)),
-        ]
-        .into_iter()
-        .flatten()
-        .map(|pair| self.coord_to_idx(pair))
-    }
+/// State of an octopus partway through the flash cascade of a single step
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Octopus {
+    Charging(i32),
+    Flashing,
+    Flashed,
+}
 
-    fn diagonal_neighbors(&self, idx: usize) -> impl Iterator<Item = usize> + '_ + Clone {
-        let (r, c) = self.idx_to_coord(idx);
-        [
-            (r.gt(&0) && c.gt(&0)).then_some((r - 1, c - 1)),
-            (r.gt(&0) && c.lt(&(self.num_cols - 1))).then_some((r - 1, c + 1)),
-            (r.lt(&(self.num_rows - 1)) && c.gt(&0)).then_some((r + 1, c - 1)),
-            (r.lt(&(self.num_rows - 1)) && c.lt(&(self.num_cols - 1))).then_some((r + 1, c + 1)),
-        ]
-        .into_iter()
-        .flatten()
-        .map(|pair| self.coord_to_idx(pair))
+impl Octopus {
+    fn charge(energy: i32) -> Self {
+        match energy {
+            e if e > 9 => Self::Flashing,
+            e => Self::Charging(e),
+        }
     }
+}
 
-    fn all_neighbors(&self, idx: usize) -> impl Iterator<Item = usize> + '_ + Clone {
-        self.cardinal_neighbors(idx)
-            .chain(self.diagonal_neighbors(idx))
+/// Octopuses gain one energy per flashing neighbor and flash once their
+/// energy exceeds nine. Each octopus flashes at most once per cascade.
+fn cascade(octopuses: &Grid<Octopus>, location: Location) -> Octopus {
+    match octopuses[location] {
+        Octopus::Charging(energy) => {
+            let flashes = octopuses
+                .neighbors(location, Connectivity::Moore, Boundary::Clip)
+                .filter(|&neighbor| octopuses[neighbor] == Octopus::Flashing)
+                .count();
+
+            Octopus::charge(energy + flashes as i32)
+        }
+        Octopus::Flashing | Octopus::Flashed => Octopus::Flashed,
     }
+}
 
+impl OctopusGarden {
     fn update(&mut self) -> &Self {
-        self.data.iter_mut().for_each(|val| *val += 1);
-
-        let mut stack: Vec<usize> = Vec::with_capacity(self.num_rows * self.num_cols);
-        stack.extend(
-            self.data
-                .iter()
-                .enumerate()
-                .filter(|(_, &val)| val > 9)
-                .map(|(i, _)| i),
-        );
-
-        let mut i = 0;
-        while i != stack.len() {
-            let idx = stack.get(i).unwrap();
-
-            let neighbors = self.all_neighbors(*idx).collect_vec();
-            neighbors
-                .iter()
-                .for_each(|neighbor| self.data[*neighbor] += 1);
-
-            let extension = neighbors
-                .iter()
-                .filter(|&neighbor| self.data[*neighbor] > 9 && !stack.contains(neighbor))
-                .collect_vec();
-
-            stack.extend(extension);
-
-            self.flash_count += 1;
-            i += 1;
-        }
-
-        self.data
-            .iter_mut()
-            .filter(|val| **val > 9)
-            .for_each(|val| {
-                *val = 0;
-            });
+        let charged = self.data.map(|&energy| Octopus::charge(energy + 1));
+        let mut automaton = Automaton::new(charged, cascade);
+        automaton.run_to_fixed_point();
+
+        let octopuses = automaton.into_cells();
+        self.flash_count += octopuses
+            .iter()
+            .filter(|&&octopus| octopus == Octopus::Flashed)
+            .count() as i32;
+        self.data = octopuses.map(|octopus| match octopus {
+            Octopus::Charging(energy) => *energy,
+            Octopus::Flashing | Octopus::Flashed => 0,
+        });
 
         self
     }
@@ -128,18 +96,15 @@ impl OctopusGarden {
 }
 
 impl FromStr for OctopusGarden {
-    type Err = Box<dyn std::error::Error>;
+    type Err = grid::error::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let v: Vec<i32> = s
-            .lines()
-            .flat_map(|s| s.chars().map(|c| c.to_digit(10).unwrap() as i32))
-            .collect();
-
         Ok(Self {
-            data: v,
-            num_rows: s.lines().count(),
-            num_cols: s.lines().next().ok_or("Invalid input")?.len(),
+            data: Grid::from_str_with(s, |ch| {
+                ch.to_digit(10)
+                    .map(|n| n as i32)
+                    .ok_or(Self::Err::UnexpectedChar(ch))
+            })?,
             flash_count: 0,
         })
     }
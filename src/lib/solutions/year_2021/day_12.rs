@@ -1,13 +1,114 @@
+use crate::utils::graph::{Edge, Graph};
 use crate::utils::solution::Solution;
 
 pub struct Day12 {}
 
 impl Solution for Day12 {
-    fn part_one(&self, _input: &str) -> Option<String> {
-        todo!()
+    fn part_one(&self, input: &str) -> Option<String> {
+        let caves = parse_caves(input)?;
+        Some(count_paths(&caves, "start", &mut Vec::new(), false).to_string())
     }
 
-    fn part_two(&self, _input: &str) -> Option<String> {
-        todo!()
+    fn part_two(&self, input: &str) -> Option<String> {
+        let caves = parse_caves(input)?;
+        Some(count_paths(&caves, "start", &mut Vec::new(), true).to_string())
     }
 }
+
+type Caves<'a> = Graph<&'a str, ()>;
+
+/// Parses the passages between caves, rejecting any that joins two big
+/// caves since paths could then bounce between them forever
+fn parse_caves(input: &str) -> Option<Caves<'_>> {
+    let mut caves = Graph::undirected();
+    for line in input.lines() {
+        let (start, end) = line.split_once('-')?;
+        if !is_small(start) && !is_small(end) {
+            return None;
+        }
+        caves.add_edge(Edge::new(start, end));
+    }
+
+    Some(caves)
+}
+
+fn is_small(cave: &str) -> bool {
+    cave.chars().all(|ch| ch.is_ascii_lowercase())
+}
+
+/// Counts the paths from `cave` to `end` that enter each small cave at most
+/// once, except for a single small cave (other than `start`) which may be
+/// entered twice while `revisit` is allowed. `visited` holds the small caves
+/// on the path so far.
+fn count_paths<'a>(
+    caves: &Caves<'a>,
+    cave: &'a str,
+    visited: &mut Vec<&'a str>,
+    revisit: bool,
+) -> usize {
+    if cave == "end" {
+        return 1;
+    }
+
+    let small = is_small(cave);
+    if small {
+        visited.push(cave);
+    }
+
+    let paths = caves
+        .neighbors(&cave)
+        .filter(|&&next| next != "start")
+        .map(|&next| match (visited.contains(&next), revisit) {
+            (false, _) => count_paths(caves, next, visited, revisit),
+            (true, true) => count_paths(caves, next, visited, false),
+            (true, false) => 0,
+        })
+        .sum();
+
+    if small {
+        visited.pop();
+    }
+
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL: &str = "start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end";
+
+    const LARGER: &str = "dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc";
+
+    #[test]
+    fn examples() {
+        assert_eq!(Day12 {}.part_one(SMALL), Some("10".to_string()));
+        assert_eq!(Day12 {}.part_two(SMALL), Some("36".to_string()));
+        assert_eq!(Day12 {}.part_one(LARGER), Some("19".to_string()));
+        assert_eq!(Day12 {}.part_two(LARGER), Some("103".to_string()));
+    }
+
+    #[test]
+    fn adjacent_big_caves() {
+        const INPUT: &str = "start-A\nA-B\nB-end";
+        assert_eq!(Day12 {}.part_one(INPUT), None);
+        assert_eq!(Day12 {}.part_two(INPUT), None);
+    }
+
+    crate::verify!(Day12, crate::my_input!("2021", "12"), "", "");
+}
@@ -1,5 +1,9 @@
-use std::{cmp::Ordering, collections::HashSet, fmt::Display, str::FromStr};
+use crate::utils::ascii;
+use crate::utils::grid::SparseGrid;
 use crate::utils::solution::Solution;
+use crate::Error;
+use glam::IVec2;
+use std::{cmp::Ordering, str::FromStr};
 
 pub struct Day13 {}
 
@@ -15,16 +19,14 @@ impl Solution for Day13 {
     }
 
     fn part_two(&self, input: &str) -> Option<String> {
-        let i = Instructions::from_str(input).unwrap().fold_all();
-        println!("{}", i);
-
-        None
+        let paper = Instructions::from_str(input).ok()?.fold_all().paper;
+        ascii::decode_points(paper.positions().map(|IVec2 { x, y }| (x, y))).ok()
     }
 }
 
 #[derive(Clone, Debug)]
 struct Instructions {
-    paper: HashSet<(i32, i32)>,
+    paper: SparseGrid<()>,
     folds: Vec<Fold>,
 }
 
@@ -39,10 +41,10 @@ impl Instructions {
         if let Some(fold) = self.folds.pop() {
             self.paper = self
                 .paper
-                .iter()
-                .map(|&(x, y)| match fold {
-                    Fold::Horizontal(n) => (fold_value(x, n), y),
-                    Fold::Vertical(n) => (x, fold_value(y, n)),
+                .positions()
+                .map(|IVec2 { x, y }| match fold {
+                    Fold::Horizontal(n) => IVec2::new(fold_value(x, n), y),
+                    Fold::Vertical(n) => IVec2::new(x, fold_value(y, n)),
                 })
                 .collect();
         }
@@ -63,53 +65,29 @@ impl Instructions {
     }
 }
 
-impl Display for Instructions {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let max_x = *self.paper.iter().map(|(x, _)| x).max().unwrap();
-        let max_y = *self.paper.iter().map(|(_, y)| y).max().unwrap();
-        for y in 0..=max_y {
-            for x in 0..=max_x {
-                if self.paper.contains(&(x, y)) {
-                    write!(f, "#")?;
-                } else {
-                    write!(f, " ")?;
-                }
-            }
-            writeln!(f)?;
-        }
-
-        Ok(())
-    }
-}
-
 impl FromStr for Instructions {
-    type Err = Box<dyn std::error::Error>;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let (points, folds_str) = s.split_once("\n\n").ok_or("Invalid input")?;
 
-        let mut paper = HashSet::new();
+        let mut paper = SparseGrid::new();
         for line in points.lines() {
-            let (x, y) = line
-                .split_once(',')
-                .ok_or(format!("Malformed point: {}", line))?;
+            let (x, y) = line.split_once(',').ok_or("Malformed point")?;
 
-            paper.insert((x.parse()?, y.parse()?));
+            paper.insert(IVec2::new(x.parse()?, y.parse()?), ());
         }
 
         let mut folds: Vec<Fold> = Vec::new();
         for line in folds_str.lines().rev() {
-            let token = line
-                .split_whitespace()
-                .nth(2)
-                .ok_or(format!("Malformed fold: {}", line))?;
+            let token = line.split_whitespace().nth(2).ok_or("Malformed fold")?;
 
-            let (dir, num) = token.split_once('=').unwrap();
+            let (dir, num) = token.split_once('=').ok_or("Malformed fold")?;
 
             folds.push(match dir {
                 "x" => Fold::Horizontal(num.parse()?),
                 "y" => Fold::Vertical(num.parse()?),
-                dir => return Err(format!("Invalid fold direction: {}", dir).into()),
+                _ => Err("Invalid fold direction")?,
             });
         }
 
@@ -158,7 +136,29 @@ fold along x=5";
         }
 
         #[test]
-        fn example2() {}
+        fn example2() {
+            // An `A` whose lower half is folded up from below the paper's middle
+            const LETTER: &str = "1,0
+2,0
+0,1
+3,1
+0,2
+3,2
+0,9
+3,9
+0,10
+3,10
+0,11
+1,11
+2,11
+3,11
+1,14
+
+fold along y=7
+fold along x=5";
+
+            assert_eq!(Day13 {}.part_two(LETTER), Some("A".to_string()));
+        }
     }
 
     crate::verify!(Day13, crate::my_input!("2021", "13"), "", "");
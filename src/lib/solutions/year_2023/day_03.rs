@@ -1,3 +1,4 @@
+use crate::utils::grid::{self, Grid, Location};
 use crate::utils::solution::Solution;
 use std::{collections::HashMap, str::FromStr};
 
@@ -50,20 +51,15 @@ impl EngineParts {
 }
 
 #[derive(Clone, Debug)]
-struct Engine(Vec<Vec<char>>);
+struct Engine(Grid<char>);
 
 impl Engine {
     fn parts(&self) -> EngineParts {
-        let num_cols = match self.0.first() {
-            Some(row) => row.len(),
-            None => 0,
-        };
+        let num_cols = self.0.cols();
 
         let mut parts: HashMap<(usize, usize), Part> = HashMap::new();
 
-        for row_idx in 0..self.0.len() {
-            let row = self.0.get(row_idx).unwrap();
-
+        for (row_idx, row) in self.0.iter_rows().enumerate() {
             let mut col_idx = 0;
 
             'columns: while (0..num_cols).contains(&col_idx) {
@@ -78,7 +74,7 @@ impl Engine {
                 }
 
                 let lower_row = ((row_idx as i32) - 1).max(0) as usize;
-                let upper_row = (row_idx + 1).min(self.0.len() - 1);
+                let upper_row = (row_idx + 1).min(self.0.rows() - 1);
 
                 let lower_col = ((col_idx as i32) - 1).max(0) as usize;
                 let upper_col = (col_idx + word_len).min(num_cols - 1);
@@ -91,14 +87,15 @@ impl Engine {
                             continue;
                         }
 
-                        let adjacent_char = self.0[adjacent_row][adjacent_col];
+                        let adjacent_char =
+                            self.0[Location::Coordinate(adjacent_row, adjacent_col)];
                         let is_symbol = !adjacent_char.is_numeric() && adjacent_char != '.';
 
                         if !is_symbol {
                             continue;
                         }
 
-                        let number = self.0[row_idx][col_idx..(col_idx + word_len)]
+                        let number = row[col_idx..(col_idx + word_len)]
                             .iter()
                             .collect::<String>();
 
@@ -122,10 +119,10 @@ impl Engine {
 }
 
 impl FromStr for Engine {
-    type Err = &'static str;
+    type Err = grid::error::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(s.lines().map(|line| line.chars().collect()).collect()))
+        Grid::from_str(s).map(Self)
     }
 }
 
@@ -82,20 +82,6 @@ impl Card {
     /// Compute the point value of the card. Starting at 1 point for the first
     /// matching number, the point value doubles for each additional match. A
     /// [`Card`] with no matching numbers is worth zero points.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use std::collections::HashSet;
-    /// use advent::solutions::year_2023::day_04::Card;
-    ///
-    /// let winning_numbers = HashSet::from([41, 48, 83, 86, 17]);
-    /// let have_numbers = HashSet::from([83, 86, 6, 31, 17, 9, 48, 53]);
-    /// let card = Card::new(winning_numbers, have_numbers);
-    ///
-    /// // 17, 48, 83, and 86 all match, so the card is worth 8 points
-    /// assert_eq!(card.points(), 8);
-    /// ```
     fn points(&self) -> i32 {
         let n = self.num_winning_numbers();
         match n {
@@ -129,6 +115,17 @@ impl FromStr for Card {
 mod tests {
     use super::*;
 
+    #[test]
+    fn card_points() {
+        let winning_numbers = HashSet::from([41, 48, 83, 86, 17]);
+        let have_numbers = HashSet::from([83, 86, 6, 31, 17, 9, 48, 53]);
+        let card = Card::new(winning_numbers, have_numbers);
+
+        // 17, 48, 83, and 86 all match, so the card is worth 8 points
+        assert_eq!(card.points(), 8);
+        assert_eq!(Card::new(Numbers::new(), Numbers::new()).points(), 0);
+    }
+
     #[test]
     fn example() {
         const INPUT: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
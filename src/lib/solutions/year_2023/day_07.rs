@@ -1,5 +1,5 @@
-use crate::utils::error::Error;
 use crate::utils::solution::Solution;
+use crate::Error;
 use itertools::Itertools;
 use std::cmp::Ordering;
 use std::str::FromStr;
@@ -28,9 +28,13 @@ enum HandType {
 }
 
 impl FromStr for HandType {
-    type Err = &'static str;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.chars().count() != 5 {
+            return Err(Error::InvalidInput("Expected hand to have 5 cards"));
+        }
+
         let counts = s
             .chars()
             .sorted()
@@ -51,8 +55,7 @@ impl FromStr for HandType {
                 _ => unreachable!("Excluded by number of groups"),
             },
             4 => HandType::OnePair,
-            5 => HandType::HighCard,
-            _ => Err("Expected hand to have 5 cards")?,
+            _ => HandType::HighCard,
         })
     }
 }
@@ -64,9 +67,13 @@ struct Hand {
 }
 
 impl FromStr for Hand {
-    type Err = &'static str;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.chars().all(|ch| "23456789TJQKA".contains(ch)) {
+            return Err(Error::InvalidInput("Unknown card face"));
+        }
+
         Ok(Hand {
             cards: s.to_string(),
             hand_type: HandType::from_str(s)?,
@@ -75,7 +82,13 @@ impl FromStr for Hand {
 }
 
 impl PartialOrd for Hand {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
         fn number_faces(ch: char) -> u8 {
             match ch {
                 'T' => b'9' + 1,
@@ -86,24 +99,12 @@ impl PartialOrd for Hand {
             }
         }
 
-        match self.hand_type.cmp(&other.hand_type) {
-            Ordering::Less => Some(Ordering::Less),
-            Ordering::Greater => Some(Ordering::Greater),
-            Ordering::Equal => {
-                let (s, o) = self
-                    .cards
-                    .chars()
-                    .zip(other.cards.chars())
-                    .find(|(a, b)| a != b)?;
-                number_faces(s).partial_cmp(&number_faces(o))
-            }
-        }
-    }
-}
-
-impl Ord for Hand {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+        self.hand_type.cmp(&other.hand_type).then_with(|| {
+            self.cards
+                .chars()
+                .map(number_faces)
+                .cmp(other.cards.chars().map(number_faces))
+        })
     }
 }
 
@@ -112,7 +113,7 @@ type Bid = usize;
 fn parse_bid(s: &str) -> Result<(Hand, Bid), Error> {
     let mut tokens = s.split_whitespace();
     Ok((
-        Hand::from_str(tokens.next().ok_or(Error::WrongSizeIterator)?).unwrap(),
+        Hand::from_str(tokens.next().ok_or(Error::WrongSizeIterator)?)?,
         tokens
             .next()
             .ok_or(Error::WrongSizeIterator)?
@@ -131,16 +132,17 @@ fn total_winnings<T: IntoIterator<Item = (Hand, Bid)>>(bids: T) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn types() {
-        assert_eq!(HandType::from_str(&"AAAAA"), Ok(HandType::FiveOfAKind));
-        assert_eq!(HandType::from_str(&"AA8AA"), Ok(HandType::FourOfAKind));
-        assert_eq!(HandType::from_str(&"23332"), Ok(HandType::FullHouse));
-        assert_eq!(HandType::from_str(&"TTT98"), Ok(HandType::ThreeOfAKind));
-        assert_eq!(HandType::from_str(&"23432"), Ok(HandType::TwoPair));
-        assert_eq!(HandType::from_str(&"A23A4"), Ok(HandType::OnePair));
-        assert_eq!(HandType::from_str(&"23456"), Ok(HandType::HighCard))
+        assert_eq!(HandType::from_str("AAAAA"), Ok(HandType::FiveOfAKind));
+        assert_eq!(HandType::from_str("AA8AA"), Ok(HandType::FourOfAKind));
+        assert_eq!(HandType::from_str("23332"), Ok(HandType::FullHouse));
+        assert_eq!(HandType::from_str("TTT98"), Ok(HandType::ThreeOfAKind));
+        assert_eq!(HandType::from_str("23432"), Ok(HandType::TwoPair));
+        assert_eq!(HandType::from_str("A23A4"), Ok(HandType::OnePair));
+        assert_eq!(HandType::from_str("23456"), Ok(HandType::HighCard))
     }
 
     #[test]
@@ -154,6 +156,35 @@ QQQJA 483
 
         assert_eq!(total_winnings(INPUT.lines().flat_map(parse_bid)), 6440);
     }
+
+    #[test]
+    fn malformed_input() {
+        assert!(HandType::from_str("AABB").is_err());
+        assert!(HandType::from_str("A").is_err());
+        assert!(Hand::from_str("AAXAA").is_err());
+        assert!(parse_bid("").is_err());
+        assert!(parse_bid("32T3K").is_err());
+        assert!(parse_bid("32T3 765").is_err());
+        assert!(parse_bid("32T3K x").is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn bid_never_panics(s in "\\PC*") {
+            let _ = parse_bid(&s);
+        }
+
+        #[test]
+        fn hand_bids_never_panic(s in "[2-9TJQKA]{0,7} [0-9]{0,22}") {
+            let _ = parse_bid(&s);
+        }
+
+        #[test]
+        fn identical_hands_compare_equal(s in "[2-9TJQKA]{5}") {
+            let hand = Hand::from_str(&s).unwrap();
+            prop_assert_eq!(hand.cmp(&hand.clone()), Ordering::Equal);
+        }
+    }
 }
 
 crate::verify!(Day07, crate::my_input!("2023", "Day07"), "251029473", "");
@@ -1,5 +1,7 @@
+use itertools::Itertools;
 use std::{collections::HashMap, str::FromStr};
 
+use crate::utils::graph::{Edge, Graph};
 use crate::utils::solution::Solution;
 
 pub struct Day08 {}
@@ -14,6 +16,32 @@ impl Solution for Day08 {
         let (directions, network) = parse_input(input);
         Some(network.ghost_path_length(directions).to_string())
     }
+
+    /// Network of nodes with the human path highlighted, if the network has
+    /// one
+    fn dot(&self, input: &str) -> Option<String> {
+        let (directions, network) = parse_input(input);
+        let mut path = vec!["AAA"];
+        // Past this many steps some (node, direction) state has repeated, so
+        // the walk is looping without reaching `ZZZ`
+        let limit = network.0.len() * directions.len();
+        for &direction in directions.iter().cycle().take(limit) {
+            let &node = path.last()?;
+            if node == "ZZZ" || !network.0.contains_key(node) {
+                break;
+            }
+            path.push(network.get(node, direction));
+        }
+
+        let graph = network.graph();
+        let dot = graph
+            .dot()
+            .display_labels()
+            .edge_labels()
+            .highlight_path(&path);
+
+        Some(dot.to_string())
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -58,6 +86,22 @@ impl Network {
         }]
     }
 
+    /// Edges from each node to its left and right neighbors, labelled `L` and
+    /// `R`, with nodes added in name order
+    fn graph(&self) -> Graph<&str, char> {
+        self.0
+            .iter()
+            .sorted()
+            .flat_map(|(from, [left, right])| {
+                [
+                    Edge::with_data(from.as_str(), left.as_str(), 'L'),
+                    Edge::with_data(from.as_str(), right.as_str(), 'R'),
+                ]
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
     fn human_path_length<T>(&self, directions: T) -> usize
     where
         T: IntoIterator<Item = Direction>,
@@ -110,7 +154,7 @@ impl Network {
 }
 
 impl FromStr for Network {
-    type Err = crate::utils::error::Error;
+    type Err = crate::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self(
@@ -178,6 +222,35 @@ XXX = (XXX, XXX)
         let (ghost_directions, ghost_network) = parse_input(GHOST_INPUT);
         assert_eq!(ghost_network.ghost_path_length(ghost_directions), 6);
     }
+
+    #[test]
+    fn network_graph() {
+        const INPUT: &str = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+
+        let dot = Day08 {}.dot(INPUT).unwrap();
+        assert!(dot.contains("n1 [label=\"BBB\", color=red];"));
+        assert!(dot.contains("n0 -> n1 [label=\"L\", color=red, penwidth=2];"));
+        assert!(dot.contains("n1 -> n2 [label=\"R\", color=red, penwidth=2];"));
+        assert!(dot.contains("n2 -> n2 [label=\"L\"];"));
+    }
+
+    #[test]
+    fn unreachable_destination() {
+        const INPUT: &str = "LR
+
+AAA = (AAA, AAA)
+ZZZ = (ZZZ, ZZZ)
+";
+
+        let dot = Day08 {}.dot(INPUT).unwrap();
+        assert!(dot.contains("n0 [label=\"AAA\", color=red];"));
+        assert!(dot.contains("n1 [label=\"ZZZ\"];"));
+    }
 }
 
 crate::verify!(
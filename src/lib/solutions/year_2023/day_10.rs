@@ -1,3 +1,4 @@
+use crate::utils::grid::{self, Boundary, Grid, Location};
 use crate::utils::solution::Solution;
 use core::fmt;
 use std::str::FromStr;
@@ -57,7 +58,7 @@ impl Segment {
 }
 
 impl TryFrom<char> for Segment {
-    type Error = &'static str;
+    type Error = grid::error::Error;
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
         Ok(match value {
@@ -69,22 +70,22 @@ impl TryFrom<char> for Segment {
             'F' => Self::SouthToEast,
             'S' => Self::Start,
             '.' => Self::Space,
-            _ => Err("Bad char")?,
+            _ => Err(Self::Error::UnexpectedChar(value))?,
         })
     }
 }
 
 #[derive(Clone, Copy, Debug)]
 enum Neighbor {
-    East(usize),
-    North(usize),
-    South(usize),
-    West(usize),
+    East(Location),
+    North(Location),
+    South(Location),
+    West(Location),
 }
 
 #[allow(dead_code)]
 impl Neighbor {
-    fn get(&self) -> usize {
+    fn get(&self) -> Location {
         match self {
             Self::East(n) => *n,
             Self::North(n) => *n,
@@ -94,63 +95,37 @@ impl Neighbor {
     }
 }
 
-#[derive(Clone, Debug)]
-struct Dimensions {
-    rows: usize,
-    cols: usize,
-}
-
-#[allow(dead_code)]
-impl Dimensions {
-    fn to_index(&self, (r, c): (usize, usize)) -> usize {
-        r * self.cols + c
-    }
-
-    fn to_coordinate(&self, idx: usize) -> (usize, usize) {
-        let row = idx / self.cols;
-        let col = idx % self.cols;
-
-        (row, col)
-    }
-
-    fn neighbors(&self, idx: usize) -> Vec<Neighbor> {
-        let (r, c) = self.to_coordinate(idx);
-        let mut neighbors = Vec::with_capacity(2);
-
-        if r > 0 {
-            neighbors.push(Neighbor::North(self.to_index((r - 1, c))));
-        }
-
-        if c > 0 {
-            neighbors.push(Neighbor::West(self.to_index((r, c - 1))));
-        }
-
-        if c + 1 < self.cols {
-            neighbors.push(Neighbor::East(self.to_index((r, c + 1))));
-        }
-
-        if r + 1 < self.rows {
-            neighbors.push(Neighbor::South(self.to_index((r + 1, c))));
-        }
-
-        neighbors
-    }
-}
-
 struct Network {
-    dimensions: Dimensions,
-    pipes: Vec<Segment>,
+    pipes: Grid<Segment>,
 }
 
 #[allow(dead_code)]
 impl Network {
-    fn start(&self) -> Option<usize> {
-        self.pipes.iter().position(|s| matches!(s, Segment::Start))
+    fn start(&self) -> Option<Location> {
+        self.pipes
+            .enumerate()
+            .find_map(|(location, s)| matches!(s, Segment::Start).then_some(location))
+    }
+
+    fn neighbors(&self, location: Location) -> Vec<Neighbor> {
+        [
+            ((-1, 0), Neighbor::North as fn(Location) -> Neighbor),
+            ((0, -1), Neighbor::West),
+            ((0, 1), Neighbor::East),
+            ((1, 0), Neighbor::South),
+        ]
+        .into_iter()
+        .filter_map(|(offset, neighbor)| {
+            self.pipes
+                .step(location, offset, Boundary::Clip)
+                .map(neighbor)
+        })
+        .collect()
     }
 
     fn find_length(&self) -> usize {
-        let start_idx = self.start().unwrap();
-        let mut stack = vec![start_idx];
+        let start = self.start().unwrap();
+        let mut stack = vec![start];
 
         while let Some(_site) = stack.pop() {
             todo!()
@@ -162,40 +137,28 @@ impl Network {
 
 impl fmt::Debug for Network {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "{:?}", self.dimensions)?;
-        for row in 0..self.dimensions.rows {
-            let mut s = String::with_capacity(self.dimensions.cols);
-            for col in 0..self.dimensions.cols {
-                s.push(match self.pipes[self.dimensions.to_index((row, col))] {
-                    Segment::EastToWest => '─',
-                    Segment::NorthToEast => '└',
-                    Segment::NorthToSouth => '│',
-                    Segment::NorthToWest => '┘',
-                    Segment::SouthToEast => '┌',
-                    Segment::SouthToWest => '┐',
-                    Segment::Space => ' ',
-                    Segment::Start => 'S',
-                });
-            }
-            writeln!(f, "{}", s)?;
-        }
-
-        fmt::Result::Ok(())
+        writeln!(f, "{:?}", self.pipes.dimensions())?;
+        let display = self.pipes.map(|segment| match segment {
+            Segment::EastToWest => '─',
+            Segment::NorthToEast => '└',
+            Segment::NorthToSouth => '│',
+            Segment::NorthToWest => '┘',
+            Segment::SouthToEast => '┌',
+            Segment::SouthToWest => '┐',
+            Segment::Space => ' ',
+            Segment::Start => 'S',
+        });
+
+        writeln!(f, "{}", display)
     }
 }
 
 impl FromStr for Network {
-    type Err = &'static str;
+    type Err = grid::error::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let chars = s.chars().map(Segment::try_from);
-
-        let rows = s.lines().count();
-        let cols = chars.clone().position(|s| s.is_err()).unwrap();
-
         Ok(Self {
-            dimensions: Dimensions { rows, cols },
-            pipes: chars.filter(Result::is_ok).collect::<Result<Vec<_>, _>>()?,
+            pipes: Grid::from_str_with(s, Segment::try_from)?,
         })
     }
 }
@@ -204,14 +167,6 @@ impl FromStr for Network {
 mod tests {
     use super::*;
 
-    #[test]
-    fn coordinate_conversion() {
-        let dimensions = Dimensions { rows: 10, cols: 20 };
-        for i in 0..200 {
-            assert_eq!(i, dimensions.to_index(dimensions.to_coordinate(i)));
-        }
-    }
-
     #[test]
     fn example() {
         const INPUT: &str = "7-F7-
@@ -223,15 +178,10 @@ LJ.LJ
         let network = Network::from_str(INPUT).unwrap();
         println!("{:?}", network);
 
-        let start_coord = (2, 0);
-        assert!(matches!(
-            network.pipes[network.dimensions.to_index(start_coord)],
-            Segment::Start
-        ));
+        let start_coord = Location::Coordinate(2, 0);
+        assert_eq!(network.start(), Some(start_coord));
 
-        let neighbors = network
-            .dimensions
-            .neighbors(network.dimensions.to_index(start_coord));
+        let neighbors = network.neighbors(start_coord);
 
         println!("{:?}", neighbors);
         for neighbor in neighbors {
@@ -53,11 +53,16 @@ impl Image {
 }
 
 impl FromStr for Image {
-    type Err = crate::utils::error::Error;
+    type Err = crate::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let num_rows = s.lines().count();
-        let num_cols = s.lines().next().ok_or(Self::Err::EmptyInputStr)?.chars().count();
+        let num_cols = s
+            .lines()
+            .next()
+            .ok_or(Self::Err::EmptyInputStr)?
+            .chars()
+            .count();
 
         let mut galaxies = Vec::new();
         let mut empty_rows = (0..num_rows).collect::<HashSet<_>>();
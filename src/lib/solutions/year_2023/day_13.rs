@@ -1,15 +1,88 @@
+use crate::utils::grid::{Grid, GridView};
 use crate::utils::solution::Solution;
+use std::str::FromStr;
 
 pub struct Day13 {}
 
 impl Solution for Day13 {
-    fn part_one(&self, _input: &str) -> Option<String> {
-        None
+    fn part_one(&self, input: &str) -> Option<String> {
+        summarize(input, 0)
     }
 
-    fn part_two(&self, _input: &str) -> Option<String> {
-        None
+    fn part_two(&self, input: &str) -> Option<String> {
+        summarize(input, 1)
     }
 }
 
+/// Adds the columns left of each vertical mirror and one hundred times the
+/// rows above each horizontal mirror, where every mirror must be off by
+/// exactly `smudges` cells.
+fn summarize(input: &str, smudges: usize) -> Option<String> {
+    input
+        .split("\n\n")
+        .map(|pattern| {
+            let pattern = Grid::from_str(pattern).ok()?;
+            reflection(pattern.view(), smudges)
+                .map(|rows| 100 * rows)
+                .or_else(|| reflection(pattern.transpose(), smudges))
+        })
+        .sum::<Option<usize>>()
+        .map(|total| total.to_string())
+}
+
+/// Number of rows above the horizontal line across which `pattern` is
+/// mirrored with exactly `smudges` mismatched cells.
+fn reflection(pattern: GridView<char>, smudges: usize) -> Option<usize> {
+    (1..pattern.rows()).find(|&rows_above| {
+        let height = rows_above.min(pattern.rows() - rows_above);
+        let cols = 0..pattern.cols();
+        let (Ok(above), Ok(below)) = (
+            pattern.subgrid(rows_above - height..rows_above, cols.clone()),
+            pattern.subgrid(rows_above..rows_above + height, cols),
+        ) else {
+            return false;
+        };
+
+        differences(above.flip_v(), below) == smudges
+    })
+}
+
+fn differences(lhs: GridView<char>, rhs: GridView<char>) -> usize {
+    lhs.iter().zip(rhs.iter()).filter(|(l, r)| l != r).count()
+}
+
 crate::verify!(Day13, crate::my_input!("2023", "Day13"), "", "");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+    #[test]
+    fn example() {
+        assert_eq!(Day13 {}.part_one(INPUT), Some("405".to_string()));
+        assert_eq!(Day13 {}.part_two(INPUT), Some("400".to_string()));
+    }
+
+    #[test]
+    fn reflections() {
+        let pattern = Grid::from_str("#.##..##.\n..#.##.#.\n##......#").unwrap();
+        assert_eq!(reflection(pattern.view(), 0), None);
+        assert_eq!(reflection(pattern.transpose(), 0), Some(5));
+    }
+}
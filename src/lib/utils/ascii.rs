@@ -1,3 +1,23 @@
+use crate::utils::grid::Grid;
+use num::PrimInt;
+use std::ops::Range;
+
+pub mod error {
+    use std::ops::Range;
+
+    #[derive(Clone, Debug, thiserror::Error, PartialEq, Eq)]
+    pub enum Error {
+        #[error("no font is {0} rows tall")]
+        UnsupportedHeight(usize),
+        #[error("unrecognized glyph in columns {}..{}", .0.start, .0.end)]
+        UnrecognizedGlyph(Range<usize>),
+        #[error("unexpected character {0:?}")]
+        UnexpectedChar(char),
+        #[error("points span more than {} pixels", super::MAX_PIXELS)]
+        OversizedImage,
+    }
+}
+
 /// 6-by-4 letter grids seen in [2016 Day
 /// 8](https://adventofcode.com/2016/day/8), [2019 Day
 /// 8](https://adventofcode.com/2019/day/8) and
@@ -12,105 +32,432 @@ const RAW_LETTER_FORMS: &str =
 #..#.#..#.#..#.#....#....#..#.#..#..#..#..#.#.#..#....#..#.#....#.#.....#.#..#...#...#...
 #..#.###...##..####.#.....###.#..#.###..##..#..#.####..##..#....#..#.###...##....#...####";
 
+/// Most pixels drawn for a set of points, far beyond any line of letters but
+/// small enough that a stray distant point cannot exhaust memory
+const MAX_PIXELS: usize = 1 << 24;
+
 const RAW_LETTERS: [char; 18] = [
     'A', 'B', 'C', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'O', 'P', 'R', 'S', 'U', 'Y', 'Z',
 ];
 
-/// Transpose a string on newline `'\n'` characters, padding resulting rows shorter
-/// than the longest input column with spaces.
-fn transpose_string(input: &str) -> String {
-    let lines: Vec<&str> = input.lines().collect();
-    let max_length = lines
-        .iter()
-        .map(|line| line.chars().count())
-        .max()
-        .unwrap_or(0);
-
-    let mut transposed_lines = vec![String::with_capacity(lines.len()); max_length];
-
-    for line in lines {
-        for (i, c) in line.chars().enumerate() {
-            transposed_lines[i].push(c);
-        }
+/// 10-by-6 letter grids seen in [2018 Day
+/// 10](https://adventofcode.com/2018/day/10).
+const RAW_LARGE_LETTER_FORMS: &str =
+    "..##....#####....####...######..######...####...#....#.....###..#....#..#.......#....#..#####...#####...#....#..######
+.#..#...#....#..#....#..#.......#.......#....#..#....#......#...#...#...#.......##...#..#....#..#....#..#....#.......#
+#....#..#....#..#.......#.......#.......#.......#....#......#...#..#....#.......##...#..#....#..#....#...#..#........#
+#....#..#....#..#.......#.......#.......#.......#....#......#...#.#.....#.......#.#..#..#....#..#....#...#..#.......#.
+#....#..#####...#.......#####...#####...#.......######......#...##......#.......#.#..#..#####...#####.....##.......#..
+######..#....#..#.......#.......#.......#..###..#....#......#...##......#.......#..#.#..#.......#..#......##......#...
+#....#..#....#..#.......#.......#.......#....#..#....#......#...#.#.....#.......#..#.#..#.......#...#....#..#....#....
+#....#..#....#..#.......#.......#.......#....#..#....#..#...#...#..#....#.......#...##..#.......#...#....#..#...#.....
+#....#..#....#..#....#..#.......#.......#...##..#....#..#...#...#...#...#.......#...##..#.......#....#..#....#..#.....
+#....#..#####....####...######..#........###.#..#....#...###....#....#..######..#....#..#.......#....#..#....#..######";
+
+const RAW_LARGE_LETTERS: [char; 15] = [
+    'A', 'B', 'C', 'E', 'F', 'G', 'H', 'J', 'K', 'L', 'N', 'P', 'R', 'X', 'Z',
+];
 
-        for transposed_line in transposed_lines
-            .iter_mut()
-            .take(max_length)
-            .skip(line.chars().count())
-        {
-            transposed_line.push(' ');
+/// Sets of letters drawn by puzzles, told apart by their height. Each holds
+/// every letter known to appear in puzzle answers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Font {
+    /// Letters 6 rows tall and 4 columns wide
+    Small,
+    /// Letters 10 rows tall and 6 columns wide
+    Large,
+}
+
+impl Font {
+    /// Number of rows in every letter of the font
+    pub fn height(self) -> usize {
+        match self {
+            Self::Small => 6,
+            Self::Large => 10,
         }
     }
 
-    transposed_lines.join("\n")
-}
+    /// Finds the font whose letters are `height` rows tall
+    pub fn from_height(height: usize) -> Option<Self> {
+        [Self::Small, Self::Large]
+            .into_iter()
+            .find(|font| font.height() == height)
+    }
 
-// Splits a string by applying `f` to each line. Lines where `f(line)` evaluates
-// to `true` become the split points and are excluded from the resulting groups.
-fn split_on_line_matching(input: &str, f: fn(&str) -> bool) -> Vec<String> {
-    let mut groups = Vec::new();
-    let mut current_group = Vec::new();
-
-    for line in input.lines() {
-        if f(line) {
-            if !current_group.is_empty() {
-                groups.push(current_group.join("\n"));
-                current_group.clear();
-            }
-        } else {
-            current_group.push(line);
+    /// Number of columns from the start of one letter to the start of the
+    /// next, including the blank columns between them
+    fn pitch(self) -> usize {
+        match self {
+            Self::Small => 5,
+            Self::Large => 8,
         }
     }
 
-    if !current_group.is_empty() {
-        groups.push(current_group.join("\n"));
+    /// Pairs each letter with its pixels
+    fn forms(self) -> Vec<(char, Bitmap)> {
+        let (raw_forms, letters): (&str, &[char]) = match self {
+            Self::Small => (RAW_LETTER_FORMS, &RAW_LETTERS),
+            Self::Large => (RAW_LARGE_LETTER_FORMS, &RAW_LARGE_LETTERS),
+        };
+
+        let bitmap = Bitmap::from_rows(
+            raw_forms
+                .lines()
+                .map(|line| line.chars().map(|ch| ch == '#')),
+        );
+        letters
+            .iter()
+            .copied()
+            .zip(bitmap.split_on_blank_columns())
+            .map(|(letter, columns)| (letter, bitmap.columns(columns)))
+            .collect()
     }
+}
 
-    groups
+/// How a picture is divided into letters
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Split {
+    /// Letters are separated by columns without lit pixels
+    #[default]
+    BlankColumns,
+    /// Letters start at multiples of the font's spacing, which tells apart
+    /// letters that touch
+    FixedWidth,
 }
 
-/// Converts characters encoded in 6-by-4 ASCII grids to their resulting `char` values.
+/// Reads letters from pictures of lit and unlit pixels.
+///
+/// By default, pixels are drawn as `'#'` and `'.'`, letters are separated by
+/// blank columns, and every pixel of a letter must match the font. Lowering
+/// the confidence accepts the letter differing from the picture in the
+/// fewest pixels, provided that at least that fraction of its pixels match.
 ///
 /// # Examples
 ///
 /// ```
-/// let input = ".##..\n#..#.\n#..#.\n####.\n#..#.\n#..#.\n";
-/// assert_eq!(advent::utils::ascii::graphical_chars(input), "A");
+/// use advent::utils::ascii::{Ocr, Split};
+///
+/// let ocr = Ocr::new().with_chars('#', ' ').with_split(Split::FixedWidth);
+/// let picture = "#   # ## \n#   ##  #\n # # #  #\n  #  ####\n  #  #  #\n  #  #  #";
+/// assert_eq!(ocr.read_str(picture), Ok("YA".to_string()));
 /// ```
-pub fn graphical_chars(s: &str) -> String {
-    assert!(s.is_ascii(), "Non-ascii input is not supported");
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ocr {
+    lit: char,
+    unlit: char,
+    split: Split,
+    min_confidence: f64,
+}
 
-    const SPACE_CHAR: char = '.';
+impl Default for Ocr {
+    fn default() -> Self {
+        Self {
+            lit: '#',
+            unlit: '.',
+            split: Split::default(),
+            min_confidence: 1.0,
+        }
+    }
+}
+
+impl Ocr {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads `lit` and `unlit` as the characters for each kind of pixel
+    pub fn with_chars(mut self, lit: char, unlit: char) -> Self {
+        self.lit = lit;
+        self.unlit = unlit;
+        self
+    }
 
-    fn all_space_chars(s: &str) -> bool {
-        s.chars().all(|ch| ch == SPACE_CHAR)
+    pub fn with_split(mut self, split: Split) -> Self {
+        self.split = split;
+        self
     }
 
-    let forms = split_on_line_matching(&transpose_string(RAW_LETTER_FORMS), all_space_chars)
-        .into_iter()
-        .zip(RAW_LETTERS)
-        .collect::<Vec<_>>();
+    /// Accepts letters whose best match in the font agrees on at least
+    /// `min_confidence` of its pixels, between `0.0` and `1.0`
+    pub fn with_min_confidence(mut self, min_confidence: f64) -> Self {
+        self.min_confidence = min_confidence;
+        self
+    }
+
+    /// Reads a picture with one line per row of pixels. Rows shorter than
+    /// the longest are padded with unlit pixels.
+    pub fn read_str(&self, s: &str) -> Result<String, error::Error> {
+        let rows = s
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|ch| match ch {
+                        ch if ch == self.lit => Ok(true),
+                        ch if ch == self.unlit => Ok(false),
+                        ch => Err(error::Error::UnexpectedChar(ch)),
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.read(&Bitmap::from_rows(rows))
+    }
+
+    /// Reads a picture whose `true` values are lit pixels
+    pub fn read_grid(&self, grid: &Grid<bool>) -> Result<String, error::Error> {
+        self.read(&Bitmap::from_rows(
+            grid.iter_rows().map(|row| row.iter().copied()),
+        ))
+    }
+
+    /// Reads a picture whose lit pixels sit at the given `(x, y)` columns and
+    /// rows, with `y` increasing down the page. Only the bounding box of the
+    /// points is read, so they may use any offset.
+    pub fn read_points<I, N>(&self, points: I) -> Result<String, error::Error>
+    where
+        I: IntoIterator<Item = (N, N)>,
+        N: PrimInt,
+    {
+        self.read(&Bitmap::from_points(points)?)
+    }
 
-    split_on_line_matching(&transpose_string(s), all_space_chars)
-        .iter()
-        .map(
-            |letter| match forms.iter().find(|(raw_form, _)| letter == raw_form) {
-                Some((_, ch)) => *ch,
-                None => '\0',
-            },
-        )
-        .collect::<String>()
+    /// Draws the bounding box of `points` with a line per row, as read by
+    /// [`Ocr::read_points`]
+    pub fn render_points<I, N>(&self, points: I) -> Result<String, error::Error>
+    where
+        I: IntoIterator<Item = (N, N)>,
+        N: PrimInt,
+    {
+        Ok(Bitmap::from_points(points)?.render(self.lit, self.unlit))
+    }
+
+    /// Draws a picture whose `true` values are lit pixels, as read by
+    /// [`Ocr::read_grid`]
+    pub fn render_grid(&self, grid: &Grid<bool>) -> String {
+        Bitmap::from_rows(grid.iter_rows().map(|row| row.iter().copied()))
+            .render(self.lit, self.unlit)
+    }
+
+    fn read(&self, bitmap: &Bitmap) -> Result<String, error::Error> {
+        let height = bitmap.pixels.len();
+        let font = Font::from_height(height).ok_or(error::Error::UnsupportedHeight(height))?;
+        let forms = font.forms();
+
+        let glyphs = match self.split {
+            Split::BlankColumns => bitmap.split_on_blank_columns(),
+            Split::FixedWidth => (0..bitmap.cols)
+                .step_by(font.pitch())
+                .filter_map(|start| bitmap.trim(start..(start + font.pitch()).min(bitmap.cols)))
+                .collect(),
+        };
+
+        glyphs
+            .into_iter()
+            .map(|columns| {
+                let glyph = bitmap.columns(columns.clone());
+                let area = (height * glyph.cols.max(1)) as f64;
+
+                forms
+                    .iter()
+                    .map(|(letter, form)| (letter, glyph.distance(form)))
+                    .min_by_key(|&(_, distance)| distance)
+                    .filter(|&(_, distance)| 1.0 - distance as f64 / area >= self.min_confidence)
+                    .map(|(&letter, _)| letter)
+                    .ok_or(error::Error::UnrecognizedGlyph(columns))
+            })
+            .collect()
+    }
+}
+
+/// Pixels in rows of equal length, where `true` is lit
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Bitmap {
+    pixels: Vec<Vec<bool>>,
+    cols: usize,
+}
+
+impl Bitmap {
+    /// Collects rows of pixels, padding short rows with unlit pixels
+    fn from_rows<R, P>(rows: R) -> Self
+    where
+        R: IntoIterator<Item = P>,
+        P: IntoIterator<Item = bool>,
+    {
+        let mut pixels: Vec<Vec<bool>> = rows
+            .into_iter()
+            .map(|row| row.into_iter().collect())
+            .collect();
+        let cols = pixels.iter().map(Vec::len).max().unwrap_or(0);
+        for row in &mut pixels {
+            row.resize(cols, false);
+        }
+
+        Self { pixels, cols }
+    }
+
+    /// Lights the pixels at `(x, y)` points, shifted so that the bounding box
+    /// of the points starts at the top-left corner. Fails if the bounding box
+    /// holds more than [`MAX_PIXELS`] pixels.
+    fn from_points<I, N>(points: I) -> Result<Self, error::Error>
+    where
+        I: IntoIterator<Item = (N, N)>,
+        N: PrimInt,
+    {
+        let points: Vec<(N, N)> = points.into_iter().collect();
+        let (Some(min_x), Some(min_y)) = (
+            points.iter().map(|&(x, _)| x).min(),
+            points.iter().map(|&(_, y)| y).min(),
+        ) else {
+            return Ok(Self::from_rows(Vec::<Vec<bool>>::new()));
+        };
+
+        // Offsets are taken in `N` itself, which cannot overflow for unsigned
+        // types and is checked for signed ones spanning most of their range
+        let offset = |n: N, min: N| n.checked_sub(&min).and_then(|d| d.to_usize());
+        let shifted = points
+            .into_iter()
+            .map(|(x, y)| Some((offset(x, min_x)?, offset(y, min_y)?)))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(error::Error::OversizedImage)?;
+
+        let cols = shifted.iter().map(|&(x, _)| x).max().unwrap_or(0);
+        let rows = shifted.iter().map(|&(_, y)| y).max().unwrap_or(0);
+        let (cols, rows) = (cols.saturating_add(1), rows.saturating_add(1));
+        if cols.saturating_mul(rows) > MAX_PIXELS {
+            return Err(error::Error::OversizedImage);
+        }
+
+        let mut pixels = vec![vec![false; cols]; rows];
+        for (x, y) in shifted {
+            pixels[y][x] = true;
+        }
+
+        Ok(Self { pixels, cols })
+    }
+
+    fn render(&self, lit: char, unlit: char) -> String {
+        self.pixels
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&pixel| if pixel { lit } else { unlit })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn is_blank_column(&self, col: usize) -> bool {
+        self.pixels.iter().all(|row| !row[col])
+    }
+
+    /// Narrows `columns` to exclude blank columns at either end, or returns
+    /// `None` if every column is blank
+    fn trim(&self, columns: Range<usize>) -> Option<Range<usize>> {
+        let start = columns.clone().find(|&col| !self.is_blank_column(col))?;
+        let end = columns.rev().find(|&col| !self.is_blank_column(col))?;
+        Some(start..end + 1)
+    }
+
+    /// Ranges of consecutive columns with lit pixels
+    fn split_on_blank_columns(&self) -> Vec<Range<usize>> {
+        let mut glyphs = Vec::new();
+        let mut start = None;
+
+        for col in 0..self.cols {
+            match (self.is_blank_column(col), start) {
+                (true, Some(first)) => {
+                    glyphs.push(first..col);
+                    start = None;
+                }
+                (false, None) => start = Some(col),
+                _ => {}
+            }
+        }
+
+        if let Some(first) = start {
+            glyphs.push(first..self.cols);
+        }
+
+        glyphs
+    }
+
+    fn columns(&self, columns: Range<usize>) -> Self {
+        Self {
+            pixels: self
+                .pixels
+                .iter()
+                .map(|row| row[columns.clone()].to_vec())
+                .collect(),
+            cols: columns.len(),
+        }
+    }
+
+    /// Number of pixels that differ between two bitmaps aligned at their
+    /// top-left corners, counting pixels outside either one as unlit
+    fn distance(&self, other: &Self) -> usize {
+        let lit = |bitmap: &Self, row: usize, col: usize| {
+            bitmap
+                .pixels
+                .get(row)
+                .and_then(|pixels| pixels.get(col))
+                .copied()
+                .unwrap_or(false)
+        };
+
+        let rows = self.pixels.len().max(other.pixels.len());
+        let cols = self.cols.max(other.cols);
+        (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| (row, col)))
+            .filter(|&(row, col)| lit(self, row, col) != lit(other, row, col))
+            .count()
+    }
+}
+
+/// Converts letters drawn in the 6-by-4 or 10-by-6 [`Font`] with `'#'` and
+/// `'.'` to their `char` values, choosing the font by the number of rows in
+/// `s`. See [`Ocr`] for other pictures.
+///
+/// # Examples
+///
+/// ```
+/// use advent::utils::ascii::{self, error::Error};
+///
+/// let input = ".##..\n#..#.\n#..#.\n####.\n#..#.\n#..#.\n";
+/// assert_eq!(ascii::graphical_chars(input), Ok("A".to_string()));
+///
+/// let unknown = "#.#\n.#.\n#.#\n#.#\n.#.\n#.#";
+/// assert_eq!(ascii::graphical_chars(unknown), Err(Error::UnrecognizedGlyph(0..3)));
+/// ```
+pub fn graphical_chars(s: &str) -> Result<String, error::Error> {
+    Ocr::new().read_str(s)
+}
+
+/// Reads the letters drawn by lit `(x, y)` points, such as the dots left on
+/// a puzzle's paper, in one call. See [`Ocr::read_points`].
+///
+/// # Examples
+///
+/// ```
+/// use advent::utils::ascii;
+///
+/// // An `L`, drawn away from the origin
+/// let points = (-3..3).map(|y| (10, y)).chain((11..14).map(|x| (x, 2)));
+/// assert_eq!(ascii::decode_points(points), Ok("L".to_string()));
+/// ```
+pub fn decode_points<I, N>(points: I) -> Result<String, error::Error>
+where
+    I: IntoIterator<Item = (N, N)>,
+    N: PrimInt,
+{
+    Ocr::new().read_points(points)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    #[test]
-    fn transpose_string() {
-        const INPUT: &str = "hello\nworld";
-        assert_eq!(super::transpose_string(INPUT), "hw\neo\nlr\nll\nod");
-    }
+    use crate::utils::grid::Location;
+    use std::str::FromStr;
 
     #[test]
     fn graphical_input() {
@@ -121,6 +468,167 @@ mod tests {
 #....#..#.#....#..#.#..#.#....#....#..#.
 ####.#..#.####..###..##..####.#.....###.";
 
-        assert_eq!(graphical_chars(EXAMPLE), String::from("EALGULPG"));
+        assert_eq!(graphical_chars(EXAMPLE), Ok(String::from("EALGULPG")));
+    }
+
+    #[test]
+    fn fonts() {
+        for font in [Font::Small, Font::Large] {
+            let raw_forms = match font {
+                Font::Small => RAW_LETTER_FORMS,
+                Font::Large => RAW_LARGE_LETTER_FORMS,
+            };
+            let letters = font
+                .forms()
+                .into_iter()
+                .map(|(ch, _)| ch)
+                .collect::<String>();
+
+            assert_eq!(raw_forms.lines().count(), font.height());
+            assert_eq!(graphical_chars(raw_forms), Ok(letters));
+        }
+    }
+
+    #[test]
+    fn large_letters() {
+        const EXAMPLE: &str = "#....#..######..#####...#....#
+#....#.......#..#....#..##...#
+#....#.......#..#....#..##...#
+#....#......#...#....#..#.#..#
+######.....#....#####...#.#..#
+#....#....#.....#..#....#..#.#
+#....#...#......#...#...#..#.#
+#....#..#.......#...#...#...##
+#....#..#.......#....#..#...##
+#....#..######..#....#..#....#";
+
+        assert_eq!(graphical_chars(EXAMPLE), Ok(String::from("HZRN")));
+    }
+
+    #[test]
+    fn unrecognized_input() {
+        assert_eq!(
+            graphical_chars("#\n#\n#"),
+            Err(error::Error::UnsupportedHeight(3))
+        );
+
+        // An `A` followed by a lone column
+        let input = ".##..#\n#..#.#\n#..#.#\n####.#\n#..#.#\n#..#.#";
+        assert_eq!(
+            graphical_chars(input),
+            Err(error::Error::UnrecognizedGlyph(5..6))
+        );
+    }
+
+    #[test]
+    fn configured_chars() {
+        let ocr = Ocr::new().with_chars('█', ' ');
+
+        assert_eq!(
+            ocr.read_str(" ██ \n█  █\n█  █\n████\n█  █\n█  █"),
+            Ok("A".into())
+        );
+        assert_eq!(
+            ocr.read_str(" ██ \n█..█\n█  █\n████\n█  █\n█  █"),
+            Err(error::Error::UnexpectedChar('.'))
+        );
+    }
+
+    #[test]
+    fn touching_letters() {
+        // `Y` is five columns wide, leaving no blank column before `A`
+        const TOUCHING: &str = "#...#.##.
+#...##..#
+.#.#.#..#
+..#..####
+..#..#..#
+..#..#..#";
+
+        assert!(graphical_chars(TOUCHING).is_err());
+        let ocr = Ocr::new().with_split(Split::FixedWidth);
+        assert_eq!(ocr.read_str(TOUCHING), Ok("YA".into()));
+    }
+
+    #[test]
+    fn noisy_letters() {
+        const CLEAN: &str = ".##..####
+#..#.#...
+#....###.
+#....#...
+#..#.#...
+.##..#...";
+        assert_eq!(graphical_chars(CLEAN), Ok("CF".into()));
+
+        // `C` with a stray pixel and `F` with a missing one
+        let noisy = CLEAN.replacen("#....###.", "#..#.##..", 1);
+        assert!(matches!(
+            graphical_chars(&noisy),
+            Err(error::Error::UnrecognizedGlyph(_))
+        ));
+
+        let ocr = Ocr::new().with_min_confidence(0.9);
+        assert_eq!(ocr.read_str(&noisy), Ok("CF".into()));
+        let strict = Ocr::new().with_min_confidence(0.99);
+        assert!(strict.read_str(&noisy).is_err());
+    }
+
+    #[test]
+    fn grids_and_points() {
+        let text = ".##.\n#..#\n#..#\n####\n#..#\n#..#";
+        let grid = Grid::<char>::from_str(text).unwrap().map(|&ch| ch == '#');
+        assert_eq!(Ocr::new().read_grid(&grid), Ok("A".into()));
+
+        let points = grid
+            .enumerate()
+            .filter(|(_, &lit)| lit)
+            .map(|(location, _)| location.coordinate(grid.dimensions()).unwrap())
+            .map(|(row, col)| (col, row));
+        assert_eq!(Ocr::new().read_points(points), Ok("A".into()));
+    }
+
+    #[test]
+    fn rendered_points() {
+        let ocr = Ocr::new().with_chars('#', ' ');
+        let points = [(-2_i64, 5_i64), (0, 5), (-1, 6)];
+        assert_eq!(ocr.render_points(points), Ok("# #\n # ".into()));
+        assert_eq!(ocr.render_points(Vec::<(u8, u8)>::new()), Ok("".into()));
+
+        let grid = Grid::<char>::from_str("#.\n.#")
+            .unwrap()
+            .map(|&ch| ch == '#');
+        assert_eq!(ocr.render_grid(&grid), "# \n #");
+
+        let text = ".##.\n#..#\n#...\n#...\n#..#\n.##.";
+        let points = Grid::<char>::from_str(text)
+            .unwrap()
+            .enumerate()
+            .filter(|(_, &ch)| ch == '#')
+            .map(|(location, _)| match location {
+                Location::Coordinate(row, col) => (col as u16 + 100, row as u16),
+                Location::Index(_) => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            Ocr::new().render_points(points.iter().copied()),
+            Ok(text.into())
+        );
+        assert_eq!(decode_points(points), Ok("C".into()));
+    }
+
+    #[test]
+    fn oversized_points() {
+        let ocr = Ocr::new();
+        let far = [(0_i64, 0_i64), (i64::MAX, 0)];
+        assert_eq!(ocr.render_points(far), Err(error::Error::OversizedImage));
+        let wide = [(i64::MIN, 0_i64), (i64::MAX, 0)];
+        assert_eq!(ocr.read_points(wide), Err(error::Error::OversizedImage));
+        let huge = [(u128::MAX, u128::MAX)];
+        assert_eq!(ocr.render_points(huge), Ok("#".into()));
+        let huge = [(0, 0), (usize::MAX as u128, 0)];
+        assert_eq!(decode_points(huge), Err(error::Error::OversizedImage));
+        let huge = [(0, 0), (u128::MAX, u128::MAX)];
+        assert_eq!(decode_points(huge), Err(error::Error::OversizedImage));
+        let square = [(0_u32, 0_u32), (1 << 12, 1 << 12)];
+        assert_eq!(ocr.render_points(square), Err(error::Error::OversizedImage));
     }
 }
@@ -0,0 +1,305 @@
+use crate::utils::grid::{Grid, Position, SparseGrid};
+use glam::{IVec2, IVec3, IVec4};
+use itertools::Itertools;
+use std::{collections::HashMap, hash::Hash, sync::OnceLock};
+
+/// Collection of cells updated synchronously by an [`Automaton`].
+pub trait Cells: Clone + PartialEq {
+    type Position: Copy;
+    type State;
+
+    /// Positions whose state may change during the next step.
+    fn candidates(&self) -> Vec<Self::Position>;
+
+    /// Builds the next generation by applying the new `states` computed for
+    /// every candidate position.
+    fn next_generation(&self, states: Vec<(Self::Position, Self::State)>) -> Self;
+}
+
+/// Update rule computing the next state of the cell at `position` from the
+/// current generation. Closures with the same signature implement [`Rule`].
+pub trait Rule<C: Cells> {
+    fn next_state(&self, cells: &C, position: C::Position) -> C::State;
+}
+
+impl<C: Cells, F: Fn(&C, C::Position) -> C::State> Rule<C> for F {
+    fn next_state(&self, cells: &C, position: C::Position) -> C::State {
+        self(cells, position)
+    }
+}
+
+/// Dense two-dimensional cells, where every location is a candidate.
+impl<T: Clone + PartialEq> Cells for Grid<T> {
+    type Position = crate::utils::grid::Location;
+    type State = T;
+
+    fn candidates(&self) -> Vec<Self::Position> {
+        self.locations().collect()
+    }
+
+    fn next_generation(&self, states: Vec<(Self::Position, Self::State)>) -> Self {
+        let mut next = self.clone();
+        for (location, state) in states {
+            next[location] = state;
+        }
+
+        next
+    }
+}
+
+/// Integer position on an unbounded lattice.
+pub trait Lattice: Position {
+    /// Every position differing by at most one along each axis, excluding
+    /// `self`. There are `3^n - 1` such positions in `n` dimensions.
+    fn moore_neighbors(&self) -> Vec<Self>;
+}
+
+macro_rules! impl_lattice {
+    ($vec:ty, $dimensions:expr) => {
+        impl Lattice for $vec {
+            fn moore_neighbors(&self) -> Vec<Self> {
+                static OFFSETS: OnceLock<Vec<$vec>> = OnceLock::new();
+
+                OFFSETS
+                    .get_or_init(|| {
+                        (0..$dimensions)
+                            .map(|_| -1..=1)
+                            .multi_cartesian_product()
+                            .filter(|offset| offset.iter().any(|&delta| delta != 0))
+                            .map(|offset| <$vec>::from_slice(&offset))
+                            .collect()
+                    })
+                    .iter()
+                    .map(|offset| *self + *offset)
+                    .collect()
+            }
+        }
+    };
+}
+
+impl_lattice!(IVec2, 2);
+impl_lattice!(IVec3, 3);
+impl_lattice!(IVec4, 4);
+
+/// Unbounded cells that are either alive or dead, with a position occupied
+/// for each living cell. Candidates for the next step are the living cells and
+/// their [`Lattice::moore_neighbors`].
+impl<P: Lattice> Cells for SparseGrid<(), P> {
+    type Position = P;
+    type State = bool;
+
+    fn candidates(&self) -> Vec<Self::Position> {
+        self.positions()
+            .flat_map(|position| position.moore_neighbors())
+            .chain(self.positions())
+            .unique()
+            .collect()
+    }
+
+    fn next_generation(&self, states: Vec<(Self::Position, Self::State)>) -> Self {
+        states
+            .into_iter()
+            .filter_map(|(position, alive)| alive.then_some(position))
+            .collect()
+    }
+}
+
+/// Generation at which an [`Automaton`] first revisits an earlier state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    /// First generation belonging to the cycle
+    pub start: usize,
+    /// Number of generations before the state repeats. A period of one is a
+    /// fixed point.
+    pub period: usize,
+}
+
+/// Cellular automaton advancing `cells` in lock step according to `rule`.
+///
+/// Iterating over an [`Automaton`] steps it forward and yields each new
+/// generation.
+///
+/// # Examples
+///
+/// A blinker oscillates with period two:
+/// ```
+/// use advent::utils::automaton::*;
+/// use advent::utils::grid::SparseGrid;
+/// use glam::IVec2;
+///
+/// let blinker: SparseGrid<()> = (-1..=1).map(|x| IVec2::new(x, 0)).collect();
+/// let mut life = Automaton::new(blinker, |cells: &SparseGrid<()>, p: IVec2| {
+///     let living = p.moore_neighbors().into_iter().filter(|&n| cells.contains(n));
+///     matches!((cells.contains(p), living.count()), (true, 2) | (_, 3))
+/// });
+///
+/// assert_eq!(life.find_cycle(), Cycle { start: 0, period: 2 });
+/// assert_eq!(life.nth(9).unwrap().len(), 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Automaton<C, R> {
+    cells: C,
+    rule: R,
+    generation: usize,
+}
+
+impl<C: Cells, R: Rule<C>> Automaton<C, R> {
+    pub fn new(cells: C, rule: R) -> Self {
+        Self {
+            cells,
+            rule,
+            generation: 0,
+        }
+    }
+
+    pub fn cells(&self) -> &C {
+        &self.cells
+    }
+
+    pub fn into_cells(self) -> C {
+        self.cells
+    }
+
+    /// Number of steps taken since construction
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Advances one generation, returning `true` if any cell changed.
+    pub fn step(&mut self) -> bool {
+        let states = self
+            .cells
+            .candidates()
+            .into_iter()
+            .map(|position| (position, self.rule.next_state(&self.cells, position)))
+            .collect();
+
+        let next = self.cells.next_generation(states);
+        let changed = next != self.cells;
+
+        self.cells = next;
+        self.generation += 1;
+        changed
+    }
+
+    /// Steps until a generation leaves every cell unchanged, returning the
+    /// number of steps taken. Never returns if the automaton does not settle.
+    pub fn run_to_fixed_point(&mut self) -> usize {
+        let start = self.generation;
+        while self.step() {}
+        self.generation - start
+    }
+
+    /// Steps until a previously seen generation recurs. The automaton is left
+    /// at the first repeated generation.
+    pub fn find_cycle(&mut self) -> Cycle
+    where
+        C: Hash + Eq,
+    {
+        let mut seen = HashMap::new();
+
+        while !seen.contains_key(&self.cells) {
+            seen.insert(self.cells.clone(), self.generation);
+            self.step();
+        }
+
+        let start = seen[&self.cells];
+        Cycle {
+            start,
+            period: self.generation - start,
+        }
+    }
+}
+
+impl<C: Cells, R: Rule<C>> Iterator for Automaton<C, R> {
+    type Item = C;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.step();
+        Some(self.cells.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::grid::{error::Error, Boundary, Connectivity, Location};
+
+    fn life<P: Lattice>(cells: &SparseGrid<(), P>, position: P) -> bool {
+        let living = position
+            .moore_neighbors()
+            .into_iter()
+            .filter(|&neighbor| cells.contains(neighbor))
+            .count();
+        matches!((cells.contains(position), living), (true, 2) | (_, 3))
+    }
+
+    #[test]
+    fn neighbor_counts() {
+        assert_eq!(IVec2::ZERO.moore_neighbors().len(), 8);
+        assert_eq!(IVec3::ZERO.moore_neighbors().len(), 26);
+        assert_eq!(IVec4::ZERO.moore_neighbors().len(), 80);
+        assert!(!IVec3::ONE.moore_neighbors().contains(&IVec3::ONE));
+    }
+
+    #[test]
+    fn still_life() {
+        let block: SparseGrid<()> = [(0, 0), (0, 1), (1, 0), (1, 1)]
+            .into_iter()
+            .map(IVec2::from)
+            .collect();
+        let mut automaton = Automaton::new(block.clone(), life);
+
+        assert_eq!(automaton.run_to_fixed_point(), 1);
+        assert_eq!(automaton.cells(), &block);
+        assert_eq!(
+            automaton.find_cycle(),
+            Cycle {
+                start: 1,
+                period: 1
+            }
+        );
+    }
+
+    #[test]
+    fn glider_in_three_dimensions() {
+        // Advent of Code 2020 day 17, part one example
+        let glider: SparseGrid<(), IVec3> = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]
+            .into_iter()
+            .map(|(x, y)| IVec3::new(x, y, 0))
+            .collect();
+
+        let mut automaton = Automaton::new(glider, life);
+        assert_eq!(automaton.nth(5).unwrap().len(), 112);
+        assert_eq!(automaton.generation(), 6);
+    }
+
+    #[test]
+    fn dense_cycle() {
+        let grid = Grid::from_str_with(".....\n..#..\n..#..\n..#..\n.....", |ch| match ch {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(Error::UnexpectedChar(ch)),
+        })
+        .unwrap();
+
+        let rule = |cells: &Grid<bool>, location: Location| {
+            let living = cells
+                .neighbors(location, Connectivity::Moore, Boundary::Clip)
+                .filter(|&neighbor| cells[neighbor])
+                .count();
+            matches!((cells[location], living), (true, 2) | (_, 3))
+        };
+
+        let mut automaton = Automaton::new(grid.clone(), rule);
+        assert_ne!(automaton.next(), Some(grid.clone()));
+        assert_eq!(automaton.next(), Some(grid));
+        assert_eq!(
+            automaton.find_cycle(),
+            Cycle {
+                start: 2,
+                period: 2
+            }
+        );
+    }
+}
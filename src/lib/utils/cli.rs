@@ -4,6 +4,8 @@ use std::{num::ParseIntError, str::FromStr};
 const YEARS: &str = "years";
 const DAYS: &str = "days";
 const INPUT: &str = "input";
+const DOT: &str = "dot";
+const STRICT: &str = "strict";
 
 #[derive(Clone, Debug, Default)]
 pub struct CommandLineInterface {
@@ -55,6 +57,18 @@ impl CommandLineInterface {
                         })
                         .value_name("PATH"),
                 )
+                .arg(
+                    Arg::with_name(DOT)
+                        .help("Write the graph built by each day, if any, in DOT format")
+                        .long(DOT)
+                        .takes_value(true)
+                        .value_name("FILE"),
+                )
+                .arg(
+                    Arg::with_name(STRICT)
+                        .help("Fail days whose input has lines that cannot be parsed")
+                        .long(STRICT),
+                )
                 .get_matches(),
         }
     }
@@ -71,6 +85,29 @@ impl CommandLineInterface {
         self.iface.value_of(INPUT).unwrap().into()
     }
 
+    /// Whether days should fail rather than skip over malformed input
+    pub fn is_strict(&self) -> bool {
+        self.iface.is_present(STRICT)
+    }
+
+    /// Where to write the DOT description of the graph built for `year` and
+    /// `day`, if requested. When several days run, the year and day are
+    /// appended to the file stem so that each graph gets its own file.
+    pub fn get_dot_file(&self, year: i32, day: i32) -> Option<std::path::PathBuf> {
+        let path = std::path::Path::new(self.iface.value_of(DOT)?);
+        if self.get_years().len() * self.get_days().len() == 1 {
+            return Some(path.into());
+        }
+
+        let stem = path.file_stem()?.to_string_lossy();
+        let mut name = format!("{}-{}-{:02}", stem, year, day);
+        if let Some(extension) = path.extension() {
+            name = format!("{}.{}", name, extension.to_string_lossy());
+        }
+
+        Some(path.with_file_name(name))
+    }
+
     fn get_int_sequence(&self, flag: &str) -> Vec<i32> {
         match self.iface.values_of(flag) {
             Some(v) => v
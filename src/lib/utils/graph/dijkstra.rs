@@ -1,85 +1,269 @@
-use std::collections;
-
-type Cost = i32;
+use std::collections::{self, HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Add;
 
 /// Minimal Representation of a node in a shortest-path search.
 pub trait Node
 where
     Self: Sized,
 {
+    /// Weight of an edge and total of a path. [`Default::default`] must be
+    /// the cost of an empty path, and adding weights must never decrease a
+    /// cost.
+    type Cost: Ord + Add<Output = Self::Cost> + Default + Copy;
+
     /// Called by the search algorithm to determine if `&self` is the
     /// destination node. This allows for very abstract representations of a
     /// node/graph where stopping criteria may not simply be a node's name
     fn is_destination(&self) -> bool;
 
-    fn neighbors(&self) -> Vec<(Cost, Self)>;
+    fn neighbors(&self) -> Vec<(Self::Cost, Self)>;
+
+    /// Lower bound on the cost of reaching a destination from `&self`, used
+    /// by [`a_star`] to explore promising nodes first. The estimate must never
+    /// exceed the true remaining cost or [`a_star`] may miss the optimum. The
+    /// default of zero makes [`a_star`] equivalent to [`search`].
+    fn heuristic(&self) -> Self::Cost {
+        Self::Cost::default()
+    }
 }
 
+/// Queued node ordered so that the smallest `cost + estimate` is popped first
+/// from a max-heap.
 #[derive(Debug)]
-pub struct ShortestPathEntry<N> {
-    cost: Cost,
+pub struct ShortestPathEntry<C, N> {
+    cost: C,
+    estimate: C,
     node: N,
 }
 
-impl<N> ShortestPathEntry<N> {
+impl<C: Ord + Add<Output = C> + Default + Copy, N> ShortestPathEntry<C, N> {
     fn new(node: N) -> Self {
-        ShortestPathEntry { cost: 0, node }
+        ShortestPathEntry {
+            cost: C::default(),
+            estimate: C::default(),
+            node,
+        }
+    }
+
+    fn with_estimate(self, estimate: C) -> Self {
+        ShortestPathEntry { estimate, ..self }
+    }
+
+    fn priority(&self) -> C {
+        self.cost + self.estimate
     }
 }
 
-impl<N> From<(Cost, N)> for ShortestPathEntry<N> {
-    fn from((cost, node): (Cost, N)) -> Self {
-        ShortestPathEntry { cost, node }
+impl<C: Default, N> From<(C, N)> for ShortestPathEntry<C, N> {
+    fn from((cost, node): (C, N)) -> Self {
+        ShortestPathEntry {
+            cost,
+            estimate: C::default(),
+            node,
+        }
     }
 }
 
-impl<N> PartialOrd for ShortestPathEntry<N> {
+impl<C: Ord + Add<Output = C> + Default + Copy, N> PartialOrd for ShortestPathEntry<C, N> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(other.cost.cmp(&self.cost))
+        Some(self.cmp(other))
     }
 }
 
-impl<N> Ord for ShortestPathEntry<N> {
+impl<C: Ord + Add<Output = C> + Default + Copy, N> Ord for ShortestPathEntry<C, N> {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        other.cost.cmp(&self.cost)
+        other.priority().cmp(&self.priority())
     }
 }
 
-impl<N> PartialEq for ShortestPathEntry<N> {
+impl<C: Ord + Add<Output = C> + Default + Copy, N> PartialEq for ShortestPathEntry<C, N> {
     fn eq(&self, other: &Self) -> bool {
-        self.cost == other.cost
+        self.priority() == other.priority()
     }
 }
 
-impl<N> Eq for ShortestPathEntry<N> {}
+impl<C: Ord + Add<Output = C> + Default + Copy, N> Eq for ShortestPathEntry<C, N> {}
 
-#[allow(dead_code)]
-pub fn search<T: Node>(begin: T) -> Cost {
-    let mut queue = collections::BinaryHeap::<ShortestPathEntry<T>>::new();
+/// Finds the cost of reaching the nearest destination from `begin`, or `None`
+/// if no destination is reachable. Each node is expanded at most once, at its
+/// optimal cost, so the search terminates on finite graphs containing cycles.
+pub fn search<T: Node + Clone + Eq + Hash>(begin: T) -> Option<T::Cost> {
+    best_first(begin, |_| T::Cost::default())
+}
 
-    queue.push(ShortestPathEntry::new(begin));
+/// Finds the cost of reaching the nearest destination from `begin` like
+/// [`search`], but expands nodes in order of their cost so far plus
+/// [`Node::heuristic`], skipping nodes that cannot lead to a cheaper
+/// destination.
+pub fn a_star<T: Node + Clone + Eq + Hash>(begin: T) -> Option<T::Cost> {
+    best_first(begin, T::heuristic)
+}
+
+fn best_first<T, H>(begin: T, heuristic: H) -> Option<T::Cost>
+where
+    T: Node + Clone + Eq + Hash,
+    H: Fn(&T) -> T::Cost,
+{
+    let mut best = HashMap::from([(begin.clone(), T::Cost::default())]);
+    let mut queue = collections::BinaryHeap::new();
+
+    let estimate = heuristic(&begin);
+    queue.push(ShortestPathEntry::new(begin).with_estimate(estimate));
+
+    while let Some(ShortestPathEntry { cost, node, .. }) = queue.pop() {
+        if best.get(&node).is_some_and(|&known| cost > known) {
+            continue;
+        }
 
-    while let Some(ShortestPathEntry { cost, node }) = queue.pop() {
         if node.is_destination() {
-            return cost;
+            return Some(cost);
+        }
+
+        for (weight, neighbor) in node.neighbors() {
+            let next = cost + weight;
+            if best.get(&neighbor).is_none_or(|&known| next < known) {
+                best.insert(neighbor.clone(), next);
+                let estimate = heuristic(&neighbor);
+                queue.push(ShortestPathEntry::from((next, neighbor)).with_estimate(estimate));
+            }
         }
+    }
 
-        let neighbors: Vec<_> = node
-            .neighbors()
-            .into_iter()
-            .map(|(weight, neighbor)| ShortestPathEntry::from((cost + weight, neighbor)))
-            .collect();
+    None
+}
+
+/// Optimal costs and predecessors discovered while searching from `origin`
+/// up to the cost of the cheapest destination.
+struct Exploration<N: Node> {
+    origin: N,
+    cost: N::Cost,
+    destinations: Vec<N>,
+    /// Every neighbor through which a node is reached at its optimal cost.
+    /// The first entry is the neighbor that first reached it.
+    predecessors: HashMap<N, Vec<N>>,
+}
 
-        queue.extend(neighbors)
+fn explore<T: Node + Clone + Eq + Hash>(begin: T) -> Option<Exploration<T>> {
+    let mut best = HashMap::from([(begin.clone(), T::Cost::default())]);
+    let mut predecessors: HashMap<T, Vec<T>> = HashMap::new();
+    let mut destinations = Vec::new();
+    let mut limit = None;
+
+    let mut queue = collections::BinaryHeap::new();
+    queue.push(ShortestPathEntry::new(begin.clone()));
+
+    while let Some(ShortestPathEntry { cost, node, .. }) = queue.pop() {
+        if limit.is_some_and(|limit| cost > limit) {
+            break;
+        }
+
+        if best.get(&node).is_some_and(|&known| cost > known) {
+            continue;
+        }
+
+        if node.is_destination() {
+            limit = Some(cost);
+            destinations.push(node);
+            continue;
+        }
+
+        for (weight, neighbor) in node.neighbors() {
+            let next = cost + weight;
+            match best.get(&neighbor) {
+                Some(&known) if next > known => {}
+                Some(&known) if next == known => {
+                    predecessors.entry(neighbor).or_default().push(node.clone());
+                }
+                _ => {
+                    best.insert(neighbor.clone(), next);
+                    predecessors.insert(neighbor.clone(), vec![node.clone()]);
+                    queue.push(ShortestPathEntry::from((next, neighbor)));
+                }
+            }
+        }
+    }
+
+    limit.map(|cost| Exploration {
+        origin: begin,
+        cost,
+        destinations,
+        predecessors,
+    })
+}
+
+/// Finds the cost of reaching the nearest destination from `begin` along with
+/// the nodes visited on the way, starting with `begin` and ending with the
+/// destination. Returns `None` if no destination is reachable.
+pub fn search_path<T: Node + Clone + Eq + Hash>(begin: T) -> Option<(T::Cost, Vec<T>)> {
+    let Exploration {
+        origin,
+        cost,
+        mut destinations,
+        predecessors,
+    } = explore(begin)?;
+
+    let mut path = vec![destinations.swap_remove(0)];
+    while let Some(previous) = path
+        .last()
+        .filter(|&node| *node != origin)
+        .and_then(|node| predecessors.get(node))
+        .and_then(|previous| previous.first())
+    {
+        path.push(previous.clone());
     }
 
-    unreachable!()
+    path.reverse();
+    Some((cost, path))
+}
+
+/// Finds the cost of reaching the nearest destination from `begin` along with
+/// every node lying on at least one optimal path to any destination of that
+/// cost. Returns `None` if no destination is reachable.
+pub fn all_shortest_paths<T: Node + Clone + Eq + Hash>(begin: T) -> Option<(T::Cost, HashSet<T>)> {
+    let Exploration {
+        origin,
+        cost,
+        destinations,
+        predecessors,
+    } = explore(begin)?;
+
+    let mut on_path: HashSet<T> = destinations.iter().cloned().collect();
+    let mut stack = destinations;
+    while let Some(node) = stack.pop() {
+        if node == origin {
+            continue;
+        }
+
+        for previous in predecessors.get(&node).into_iter().flatten() {
+            if on_path.insert(previous.clone()) {
+                stack.push(previous.clone());
+            }
+        }
+    }
+
+    Some((cost, on_path))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    impl Node for i32 {
+        type Cost = i32;
+
+        fn is_destination(&self) -> bool {
+            *self == 10
+        }
+
+        fn neighbors(&self) -> Vec<(i32, Self)> {
+            ((3 * self + 1)..=(3 * self + 3))
+                .enumerate()
+                .map(|(i, n)| ((i + 1) as i32, n))
+                .collect()
+        }
+    }
+
     #[test]
     /// Finds the shortest path from 0 to 10 amongst a trinary tree of integers
     /// such that `i` connects to `3 * i + 1` (weight = 1), `3 * i + 2` (weight
@@ -102,19 +286,165 @@ mod tests {
     /// weight(3->10) = 3 + 1 = 4 which is the shortest (and only) path from 0
     /// to 10
     fn trinary_tree() {
-        impl super::Node for i32 {
-            fn is_destination(&self) -> bool {
-                *self == 10
-            }
+        assert_eq!(search(0), Some(4));
+        assert_eq!(search_path(0), Some((4, vec![0, 3, 10])));
+    }
 
-            fn neighbors(&self) -> Vec<(i32, Self)> {
-                ((3 * self + 1)..=(3 * self + 3))
-                    .enumerate()
-                    .map(|(i, n)| ((i + 1) as Cost, n))
-                    .collect()
-            }
+    /// Location in a 3-by-3 room with a pillar in the middle, moving one step
+    /// at a time from the top-left to the bottom-right corner:
+    /// S..
+    /// .#.
+    /// ..E
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Room(i32, i32);
+
+    impl Node for Room {
+        type Cost = i32;
+
+        fn is_destination(&self) -> bool {
+            *self == Room(2, 2)
+        }
+
+        fn neighbors(&self) -> Vec<(Self::Cost, Self)> {
+            [(-1, 0), (0, -1), (0, 1), (1, 0)]
+                .into_iter()
+                .map(|(dr, dc)| Room(self.0 + dr, self.1 + dc))
+                .filter(|Room(r, c)| (0..3).contains(r) && (0..3).contains(c))
+                .filter(|room| *room != Room(1, 1))
+                .map(|room| (1, room))
+                .collect()
+        }
+
+        fn heuristic(&self) -> Self::Cost {
+            (2 - self.0) + (2 - self.1)
+        }
+    }
+
+    #[test]
+    fn path_around_pillar() {
+        let (cost, path) = search_path(Room(0, 0)).unwrap();
+
+        assert_eq!(cost, 4);
+        assert_eq!(path.len(), 5);
+        assert_eq!(path.first(), Some(&Room(0, 0)));
+        assert_eq!(path.last(), Some(&Room(2, 2)));
+        assert!(path
+            .windows(2)
+            .all(|pair| pair[0].neighbors().contains(&(1, pair[1].clone()))));
+    }
+
+    /// Position on a ring of five nodes, each connected to its two neighbors
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Ring {
+        position: u8,
+        goal: u8,
+    }
+
+    impl Node for Ring {
+        type Cost = u64;
+
+        fn is_destination(&self) -> bool {
+            self.position == self.goal
+        }
+
+        fn neighbors(&self) -> Vec<(Self::Cost, Self)> {
+            [1, 4]
+                .into_iter()
+                .map(|step| Ring {
+                    position: (self.position + step) % 5,
+                    goal: self.goal,
+                })
+                .map(|ring| (1, ring))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn cycles_and_unreachable_destinations() {
+        assert_eq!(
+            search(Ring {
+                position: 0,
+                goal: 3
+            }),
+            Some(2)
+        );
+        assert_eq!(
+            search(Ring {
+                position: 0,
+                goal: 7
+            }),
+            None
+        );
+        assert_eq!(
+            search_path(Ring {
+                position: 1,
+                goal: 7
+            }),
+            None
+        );
+        assert_eq!(
+            all_shortest_paths(Ring {
+                position: 2,
+                goal: 7
+            }),
+            None
+        );
+        assert_eq!(search(Room(0, 0)), Some(4));
+        assert_eq!(
+            a_star(Ring {
+                position: 0,
+                goal: 7
+            }),
+            None
+        );
+    }
+
+    /// Chain of hops far too long for `i32` costs
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Hop(u8);
+
+    impl Node for Hop {
+        type Cost = u64;
+
+        fn is_destination(&self) -> bool {
+            self.0 == 3
         }
 
-        assert_eq!(search(0), 4);
+        fn neighbors(&self) -> Vec<(Self::Cost, Self)> {
+            vec![(1 << 40, Hop(self.0 + 1))]
+        }
+    }
+
+    #[test]
+    fn wide_costs() {
+        assert_eq!(search(Hop(0)), Some(3 << 40));
+        assert_eq!(search_path(Hop(1)).map(|(_, path)| path.len()), Some(3));
+    }
+
+    #[test]
+    fn a_star_matches_search() {
+        assert_eq!(a_star(0), Some(4));
+        assert_eq!(a_star(Room(0, 0)), Some(4));
+        assert_eq!(a_star(Room(2, 0)), search(Room(2, 0)));
+        assert_eq!(
+            a_star(Ring {
+                position: 4,
+                goal: 1
+            }),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn every_node_on_a_shortest_path() {
+        let (cost, nodes) = all_shortest_paths(Room(0, 0)).unwrap();
+
+        assert_eq!(cost, 4);
+        assert_eq!(nodes.len(), 8);
+        assert!(!nodes.contains(&Room(1, 1)));
+
+        let (cost, nodes) = all_shortest_paths(0).unwrap();
+        assert_eq!(cost, 4);
+        assert_eq!(nodes, HashSet::from([0, 3, 10]));
     }
 }
@@ -0,0 +1,196 @@
+use super::Directedness;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
+
+/// Formats a node or edge data as a label
+type Label<'a, X> = Box<dyn Fn(&X) -> String + 'a>;
+
+/// Renders a graph in the Graphviz DOT language through its [`Display`]
+/// implementation. Nodes are labelled with their [`Debug`] representation
+/// unless [`Dot::display_labels`] is requested, and edges are only labelled
+/// after [`Dot::edge_labels`].
+///
+/// # Examples
+///
+/// ```
+/// use advent::utils::graph::{Edge, Graph};
+///
+/// let graph = Graph::from([Edge::with_data("a", "b", 3), Edge::with_data("b", "c", 4)]);
+/// let dot = graph.dot().display_labels().edge_labels().highlight_path([&"b", &"c"]);
+///
+/// assert_eq!(
+///     dot.to_string(),
+///     r#"digraph {
+///     n0 [label="a"];
+///     n1 [label="b", color=red];
+///     n2 [label="c", color=red];
+///     n0 -> n1 [label="3"];
+///     n1 -> n2 [label="4", color=red, penwidth=2];
+/// }
+/// "#
+/// );
+/// ```
+pub struct Dot<'a, T, U> {
+    nodes: Vec<&'a T>,
+    edges: Vec<(usize, usize, &'a U)>,
+    directedness: Directedness,
+    node_label: Label<'a, T>,
+    edge_label: Option<Label<'a, U>>,
+    path: Vec<usize>,
+}
+
+impl<'a, T: Debug + Eq + Hash, U> Dot<'a, T, U> {
+    /// Describes the `edges` between `nodes`, given as indices into `nodes`
+    pub(super) fn new(
+        nodes: Vec<&'a T>,
+        edges: Vec<(usize, usize, &'a U)>,
+        directedness: Directedness,
+    ) -> Self {
+        Self {
+            nodes,
+            edges,
+            directedness,
+            node_label: Box::new(|node| format!("{:?}", node)),
+            edge_label: None,
+            path: Vec::new(),
+        }
+    }
+
+    /// Draws every edge with or without an arrow, and highlights edges of the
+    /// path followed in either direction when undirected
+    pub fn with_directedness(mut self, directedness: Directedness) -> Self {
+        self.directedness = directedness;
+        self
+    }
+
+    /// Labels nodes with their [`Display`] representation
+    pub fn display_labels(mut self) -> Self
+    where
+        T: Display,
+    {
+        self.node_label = Box::new(|node| node.to_string());
+        self
+    }
+
+    /// Labels edges with the [`Display`] representation of their data
+    pub fn edge_labels(mut self) -> Self
+    where
+        U: Display,
+    {
+        self.edge_label = Some(Box::new(|data| data.to_string()));
+        self
+    }
+
+    /// Highlights the nodes of `path` and the edges between consecutive
+    /// nodes, such as a tour. Nodes not in the graph are ignored.
+    pub fn highlight_path<'b, I>(mut self, path: I) -> Self
+    where
+        I: IntoIterator<Item = &'b T>,
+        T: 'b,
+    {
+        let indices: HashMap<&T, usize> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(index, &node)| (node, index))
+            .collect();
+
+        self.path = path
+            .into_iter()
+            .filter_map(|node| indices.get(node).copied())
+            .collect();
+        self
+    }
+}
+
+impl<T, U> Display for Dot<'_, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (keyword, arrow) = match self.directedness {
+            Directedness::Directed => ("digraph", "->"),
+            Directedness::Undirected => ("graph", "--"),
+        };
+
+        let on_path: HashSet<usize> = self.path.iter().copied().collect();
+        let mut steps: HashSet<(usize, usize)> = HashSet::new();
+        for pair in self.path.windows(2) {
+            steps.insert((pair[0], pair[1]));
+            if self.directedness == Directedness::Undirected {
+                steps.insert((pair[1], pair[0]));
+            }
+        }
+
+        writeln!(f, "{} {{", keyword)?;
+        for (index, node) in self.nodes.iter().enumerate() {
+            write!(
+                f,
+                "    n{} [label=\"{}\"",
+                index,
+                escape(&(self.node_label)(node))
+            )?;
+            if on_path.contains(&index) {
+                write!(f, ", color=red")?;
+            }
+            writeln!(f, "];")?;
+        }
+
+        for &(start, end, data) in &self.edges {
+            let mut attributes = Vec::new();
+            if let Some(label) = &self.edge_label {
+                attributes.push(format!("label=\"{}\"", escape(&label(data))));
+            }
+            if steps.contains(&(start, end)) {
+                attributes.push("color=red, penwidth=2".to_string());
+            }
+
+            write!(f, "    n{} {} n{}", start, arrow, end)?;
+            if !attributes.is_empty() {
+                write!(f, " [{}]", attributes.join(", "))?;
+            }
+            writeln!(f, ";")?;
+        }
+
+        writeln!(f, "}}")
+    }
+}
+
+/// Escapes `label` for use inside a quoted DOT string
+fn escape(label: &str) -> String {
+    label
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undirected_escaped_labels() {
+        let nodes = vec![&"say \"hi\"", &"bye"];
+        let dot = Dot::new(nodes, vec![(0, 1, &())], Directedness::Undirected)
+            .display_labels()
+            .highlight_path([&"bye", &"say \"hi\"", &"unknown"]);
+
+        assert_eq!(
+            dot.to_string(),
+            r#"graph {
+    n0 [label="say \"hi\"", color=red];
+    n1 [label="bye", color=red];
+    n0 -- n1 [color=red, penwidth=2];
+}
+"#
+        );
+    }
+
+    #[test]
+    fn debug_labels() {
+        let dot = Dot::new(vec![&'x', &'y'], vec![(1, 0, &5)], Directedness::Directed);
+
+        assert_eq!(
+            dot.to_string(),
+            "digraph {\n    n0 [label=\"'x'\"];\n    n1 [label=\"'y'\"];\n    n1 -> n0;\n}\n"
+        );
+    }
+}
@@ -0,0 +1,302 @@
+use super::{Edge, Graph};
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, Sub};
+
+/// Greatest flow from a source to a sink that respects every edge's capacity.
+#[derive(Clone, Debug)]
+pub struct MaxFlow<T, C> {
+    /// Total flow leaving the source
+    pub value: C,
+    /// Flow along each edge that carries any, oriented the way it flows
+    pub flows: Vec<Edge<T, C>>,
+    /// Capacity left on every edge, including the reverse of each edge
+    /// carrying flow, which could cancel that flow
+    pub residual: Graph<T, C>,
+    /// Edges of minimum total capacity whose removal separates the sink from
+    /// the source
+    pub cut: MinCut<T, C>,
+}
+
+/// An s–t cut whose total capacity equals the maximum flow.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MinCut<T, C> {
+    /// Edges from the source's side to the sink's side, with their capacities
+    pub edges: Vec<Edge<T, C>>,
+    /// Number of nodes still reachable from the source in the residual graph
+    pub source_side: usize,
+    /// Number of nodes on the sink's side
+    pub sink_side: usize,
+}
+
+/// Residual capacities of arcs, listed by the node each leaves, stored in
+/// pairs so that arc `a ^ 1` is the reverse of arc `a`.
+struct Network<C> {
+    outgoing: Vec<Vec<usize>>,
+    heads: Vec<usize>,
+    capacity: Vec<C>,
+}
+
+impl<C> Network<C>
+where
+    C: Copy + Ord + Default + Add<Output = C> + Sub<Output = C>,
+{
+    fn new(n: usize) -> Self {
+        Self {
+            outgoing: vec![Vec::new(); n],
+            heads: Vec::new(),
+            capacity: Vec::new(),
+        }
+    }
+
+    /// Adds an arc and its reverse, returning the index of the former
+    fn add_arc(&mut self, start: usize, end: usize, capacity: C, reverse: C) -> usize {
+        let arc = self.heads.len();
+        self.heads.extend([end, start]);
+        self.capacity.extend([capacity, reverse]);
+        self.outgoing[start].push(arc);
+        self.outgoing[end].push(arc ^ 1);
+        arc
+    }
+
+    fn tail(&self, arc: usize) -> usize {
+        self.heads[arc ^ 1]
+    }
+
+    /// Finds a shortest path with spare capacity from `source` to every node
+    /// it reaches, returning the arc used to arrive at each node.
+    fn search(&self, source: usize) -> Vec<Option<usize>> {
+        let mut arrivals = vec![None; self.outgoing.len()];
+        let mut seen = vec![false; self.outgoing.len()];
+        seen[source] = true;
+
+        let mut queue = VecDeque::from([source]);
+        while let Some(node) = queue.pop_front() {
+            for &arc in &self.outgoing[node] {
+                let head = self.heads[arc];
+                if !seen[head] && self.capacity[arc] > C::default() {
+                    seen[head] = true;
+                    arrivals[head] = Some(arc);
+                    queue.push_back(head);
+                }
+            }
+        }
+
+        arrivals
+    }
+
+    /// Saturates shortest augmenting paths until the sink is unreachable,
+    /// following the Edmonds–Karp algorithm, and returns the total flow.
+    fn saturate(&mut self, source: usize, sink: usize) -> C {
+        let mut total = C::default();
+        loop {
+            let arrivals = self.search(source);
+            if arrivals[sink].is_none() {
+                return total;
+            }
+
+            let mut path = Vec::new();
+            let mut node = sink;
+            while let Some(arc) = arrivals[node] {
+                path.push(arc);
+                node = self.tail(arc);
+            }
+
+            let bottleneck = path
+                .iter()
+                .map(|&arc| self.capacity[arc])
+                .min()
+                .unwrap_or_default();
+
+            for arc in path {
+                self.capacity[arc] = self.capacity[arc] - bottleneck;
+                self.capacity[arc ^ 1] = self.capacity[arc ^ 1] + bottleneck;
+            }
+            total = total + bottleneck;
+        }
+    }
+}
+
+/// Pushes as much flow as possible from `source` to `sink` through `edges`
+/// between `nodes`, given as indices into `nodes`. Each edge of an undirected
+/// network may carry flow in either direction.
+pub(super) fn max_flow<T, C>(
+    nodes: &[T],
+    edges: &[(usize, usize, C)],
+    undirected: bool,
+    source: usize,
+    sink: usize,
+) -> MaxFlow<T, C>
+where
+    T: Clone + Debug + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C> + Sub<Output = C>,
+{
+    let mut network = Network::new(nodes.len());
+    let arcs: Vec<usize> = edges
+        .iter()
+        .map(|&(start, end, capacity)| {
+            let reverse = if undirected { capacity } else { C::default() };
+            network.add_arc(start, end, capacity, reverse)
+        })
+        .collect();
+
+    let value = network.saturate(source, sink);
+
+    let flows = edges
+        .iter()
+        .zip(&arcs)
+        .filter_map(|(&(start, end, capacity), &arc)| {
+            let remaining = network.capacity[arc];
+            if remaining < capacity {
+                Some(Edge::with_data(start, end, capacity - remaining))
+            } else if remaining > capacity {
+                Some(Edge::with_data(end, start, remaining - capacity))
+            } else {
+                None
+            }
+        })
+        .map(|edge| {
+            Edge::with_data(
+                nodes[edge.start].clone(),
+                nodes[edge.end].clone(),
+                edge.data,
+            )
+        })
+        .collect();
+
+    let residual = (0..network.heads.len())
+        .filter(|&arc| network.capacity[arc] > C::default())
+        .map(|arc| {
+            let (start, end) = (network.tail(arc), network.heads[arc]);
+            Edge::with_data(
+                nodes[start].clone(),
+                nodes[end].clone(),
+                network.capacity[arc],
+            )
+        })
+        .collect::<Vec<_>>()
+        .into();
+
+    let mut source_side = network
+        .search(source)
+        .iter()
+        .map(Option::is_some)
+        .collect::<Vec<_>>();
+    source_side[source] = true;
+
+    let crosses = |start: usize, end: usize| source_side[start] && !source_side[end];
+    let cut_edges = edges
+        .iter()
+        .filter_map(|&(start, end, capacity)| {
+            if crosses(start, end) {
+                Some((start, end, capacity))
+            } else if undirected && crosses(end, start) {
+                Some((end, start, capacity))
+            } else {
+                None
+            }
+        })
+        .map(|(start, end, capacity)| {
+            Edge::with_data(nodes[start].clone(), nodes[end].clone(), capacity)
+        })
+        .collect();
+
+    let reached = source_side.iter().filter(|&&reached| reached).count();
+    MaxFlow {
+        value,
+        flows,
+        residual,
+        cut: MinCut {
+            edges: cut_edges,
+            source_side: reached,
+            sink_side: nodes.len() - reached,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The flow network from CLRS, whose maximum flow is 23
+    const PIPES: [(usize, usize, u32); 9] = [
+        (0, 1, 16),
+        (0, 2, 13),
+        (2, 1, 4),
+        (1, 3, 12),
+        (3, 2, 9),
+        (2, 4, 14),
+        (4, 3, 7),
+        (3, 5, 20),
+        (4, 5, 4),
+    ];
+
+    #[test]
+    fn directed_flow() {
+        let nodes = ['s', 'a', 'b', 'c', 'd', 't'];
+        let flow = max_flow(&nodes, &PIPES, false, 0, 5);
+
+        assert_eq!(flow.value, 23);
+        assert_eq!(
+            flow.cut.edges,
+            vec![
+                Edge::with_data('a', 'c', 12),
+                Edge::with_data('d', 'c', 7),
+                Edge::with_data('d', 't', 4),
+            ]
+        );
+        assert_eq!((flow.cut.source_side, flow.cut.sink_side), (4, 2));
+
+        let into_sink: u32 = flow
+            .flows
+            .iter()
+            .filter(|e| e.end == 't')
+            .map(|e| e.data)
+            .sum();
+        assert_eq!(into_sink, 23);
+        for node in ['a', 'b', 'c', 'd'] {
+            let inflow: u32 = flow
+                .flows
+                .iter()
+                .filter(|e| e.end == node)
+                .map(|e| e.data)
+                .sum();
+            let outflow: u32 = flow
+                .flows
+                .iter()
+                .filter(|e| e.start == node)
+                .map(|e| e.data)
+                .sum();
+            assert_eq!(inflow, outflow);
+        }
+
+        assert!(flow.residual.neighbors(&'t').all(|&node| node != 's'));
+        assert!(flow.residual.reachable(&'s').len() == 4);
+    }
+
+    #[test]
+    fn undirected_flow() {
+        // Flow must cross the 2 → 1 edge against its listed direction
+        let edges = [(0, 2, 5), (1, 2, 3), (1, 3, 4), (2, 3, 1)];
+        let flow = max_flow(&[0, 1, 2, 3], &edges, true, 0, 3);
+
+        assert_eq!(flow.value, 4);
+        assert!(flow.flows.contains(&Edge::with_data(2, 1, 3)));
+        assert_eq!(
+            flow.cut.edges,
+            vec![Edge::with_data(2, 1, 3), Edge::with_data(2, 3, 1)]
+        );
+        assert_eq!((flow.cut.source_side, flow.cut.sink_side), (2, 2));
+    }
+
+    #[test]
+    fn disconnected_sink() {
+        let flow = max_flow(&['s', 'a', 't'], &[(0, 1, 3)], false, 0, 2);
+
+        assert_eq!(flow.value, 0);
+        assert!(flow.flows.is_empty());
+        assert!(flow.cut.edges.is_empty());
+        assert_eq!((flow.cut.source_side, flow.cut.sink_side), (2, 1));
+    }
+}
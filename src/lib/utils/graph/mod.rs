@@ -1,9 +1,13 @@
 use core::fmt::Debug;
 use itertools::Itertools;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 
 pub mod dijkstra;
+pub mod dot;
+pub mod flow;
+pub mod traversal;
+pub mod union_find;
 
 /// Details an edge in a graph between nodes `a` and `b`. The `data` field
 /// defaults to the zero-sized unit type, `()`, which is suitable for undirected
@@ -34,45 +38,327 @@ impl<T, U> Edge<T, U> {
     }
 }
 
+/// Whether the edges of a [`Graph`] can only be followed from start to end or
+/// in both directions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Directedness {
+    #[default]
+    Directed,
+    Undirected,
+}
+
 #[derive(Clone, Debug)]
 pub struct Graph<T, U> {
     pub edges: Vec<Edge<T, U>>,
 
-    /// Association of a node name with the [`Edge`]s connected to that node.
-    /// Note: [`Edge`]s may be undirected, incoming, outgoing, or symmetric
-    /// depending on context.
+    /// Association of a node name with the indices of the [`Edge`]s that
+    /// start or end at that node. A self-loop is listed once. Nodes are only
+    /// known through their edges, so a node left without edges is removed.
     pub nodes: HashMap<T, Vec<usize>>,
+
+    directedness: Directedness,
 }
 
 impl<T: Clone + Debug + Eq + Hash, U: Clone> Graph<T, U> {
+    /// Constructs an empty, directed [`Graph`]
     pub fn new() -> Self {
+        Self::with_directedness(Directedness::Directed)
+    }
+
+    /// Constructs an empty, undirected [`Graph`]
+    pub fn undirected() -> Self {
+        Self::with_directedness(Directedness::Undirected)
+    }
+
+    pub fn with_directedness(directedness: Directedness) -> Self {
         Graph {
             edges: Vec::new(),
             nodes: HashMap::new(),
+            directedness,
         }
     }
 
+    pub fn directedness(&self) -> Directedness {
+        self.directedness
+    }
+
     /// Add an edge to the graph
     pub fn add_edge(&mut self, edge: Edge<T, U>) {
-        let edge_index = self.edges.len();
-        self.edges.push(edge.clone());
+        self.edges.push(edge);
+        self.attach(self.edges.len() - 1);
+    }
+
+    /// Removes and returns the first edge from `start` to `end`, or between
+    /// them in either direction if the graph is undirected. The last edge
+    /// takes the index of the removed one.
+    pub fn remove_edge(&mut self, start: &T, end: &T) -> Option<Edge<T, U>> {
+        let index = self
+            .incident_indices(start)
+            .find(|&index| self.connects(&self.edges[index], start, end))?;
+        let last = self.edges.len() - 1;
+
+        self.detach(index);
+        if index != last {
+            self.detach(last);
+        }
+
+        let edge = self.edges.swap_remove(index);
+        if index != last {
+            self.attach(index);
+        }
+
+        Some(edge)
+    }
+
+    /// Iterates over the edges that can be followed away from `node`
+    pub fn out_edges<'a>(&'a self, node: &'a T) -> impl Iterator<Item = &'a Edge<T, U>> + 'a {
+        let undirected = self.directedness == Directedness::Undirected;
+        self.incident_edges(node)
+            .filter(move |edge| undirected || edge.start == *node)
+    }
+
+    /// Iterates over the edges that can be followed to arrive at `node`
+    pub fn in_edges<'a>(&'a self, node: &'a T) -> impl Iterator<Item = &'a Edge<T, U>> + 'a {
+        let undirected = self.directedness == Directedness::Undirected;
+        self.incident_edges(node)
+            .filter(move |edge| undirected || edge.end == *node)
+    }
+
+    /// Iterates over the nodes one edge away from `node` along
+    /// [`Graph::out_edges`]
+    pub fn neighbors<'a>(&'a self, node: &'a T) -> impl Iterator<Item = &'a T> + 'a {
+        self.out_edges(node)
+            .map(move |edge| Self::opposite(edge, node))
+    }
+
+    /// Iterates over the nodes from which `node` is one edge away along
+    /// [`Graph::in_edges`]
+    pub fn predecessors<'a>(&'a self, node: &'a T) -> impl Iterator<Item = &'a T> + 'a {
+        self.in_edges(node)
+            .map(move |edge| Self::opposite(edge, node))
+    }
+
+    /// Number of edges starting or ending at `node`
+    pub fn degree(&self, node: &T) -> usize {
+        self.nodes.get(node).map_or(0, Vec::len)
+    }
+
+    /// Number of [`Graph::out_edges`] of `node`
+    pub fn out_degree(&self, node: &T) -> usize {
+        self.out_edges(node).count()
+    }
+
+    /// Number of [`Graph::in_edges`] of `node`
+    pub fn in_degree(&self, node: &T) -> usize {
+        self.in_edges(node).count()
+    }
+
+    /// Constructs the subgraph containing every edge whose endpoints both
+    /// satisfy `predicate`
+    pub fn induced_subgraph<P: Fn(&T) -> bool>(&self, predicate: P) -> Self {
+        let mut subgraph = Self::with_directedness(self.directedness);
+        subgraph.extend(
+            self.edges
+                .iter()
+                .filter(|edge| predicate(&edge.start) && predicate(&edge.end))
+                .cloned(),
+        );
+
+        subgraph
+    }
+
+    /// Visits the nodes reachable from `start` along [`Graph::neighbors`] in
+    /// breadth-first order. See [`traversal::bfs`].
+    pub fn bfs(&self, start: &T) -> Vec<(T, usize)> {
+        traversal::bfs(start.clone(), |node| self.neighbor_list(node))
+    }
+
+    /// Explores the nodes reachable from `start` along [`Graph::neighbors`]
+    /// in depth-first order. See [`traversal::dfs`].
+    pub fn dfs(&self, start: &T) -> traversal::DepthFirst<T> {
+        traversal::dfs(start.clone(), |node| self.neighbor_list(node))
+    }
+
+    /// Collects the nodes reachable from `start` along [`Graph::neighbors`].
+    /// See [`traversal::reachable`].
+    pub fn reachable(&self, start: &T) -> HashSet<T> {
+        traversal::reachable(start.clone(), |node| self.neighbor_list(node))
+    }
+
+    /// Orders the nodes so that every edge points from an earlier node to a
+    /// later one, breaking ties by the order in which edges were added. Every
+    /// edge of an undirected graph forms a cycle. See
+    /// [`traversal::topological_sort`].
+    pub fn topological_sort(&self) -> Result<Vec<T>, traversal::CycleError<T>> {
+        traversal::topological_sort(self.node_order(), |node| self.neighbor_list(node))
+    }
+
+    /// Partitions the nodes into strongly connected components in
+    /// topological order. See [`traversal::strongly_connected_components`].
+    pub fn strongly_connected_components(&self) -> Vec<Vec<T>> {
+        traversal::strongly_connected_components(self.node_order(), |node| self.neighbor_list(node))
+    }
+
+    /// Collapses each strongly connected component into a single node of a
+    /// directed acyclic graph. See [`traversal::condensation`].
+    pub fn condensation(&self) -> traversal::Condensation<T> {
+        traversal::condensation(self.node_order(), |node| self.neighbor_list(node))
+    }
+
+    /// Finds a cycle along [`Graph::neighbors`], listing its nodes in order.
+    /// As with [`Graph::topological_sort`], every edge of an undirected graph
+    /// forms a cycle. See [`traversal::find_cycle`].
+    pub fn find_cycle(&self) -> Option<Vec<T>> {
+        traversal::find_cycle(self.node_order(), |node| self.neighbor_list(node))
+    }
+
+    /// Describes the graph in the Graphviz DOT language, numbering nodes in
+    /// the order their first edge was added. See [`dot::Dot`].
+    pub fn dot(&self) -> dot::Dot<'_, T, U> {
+        let mut nodes = Vec::new();
+        let mut indices: HashMap<&T, usize> = HashMap::new();
+        let mut index_of = |node| {
+            *indices.entry(node).or_insert_with(|| {
+                nodes.push(node);
+                nodes.len() - 1
+            })
+        };
+
+        let edges = self
+            .edges
+            .iter()
+            .map(|edge| (index_of(&edge.start), index_of(&edge.end), &edge.data))
+            .collect();
+
+        dot::Dot::new(nodes, edges, self.directedness)
+    }
+
+    /// Every node in the order its first edge was added
+    fn node_order(&self) -> impl Iterator<Item = T> + '_ {
+        self.edges
+            .iter()
+            .flat_map(|edge| [edge.start.clone(), edge.end.clone()])
+            .unique()
+    }
+
+    fn opposite<'a>(edge: &'a Edge<T, U>, node: &T) -> &'a T {
+        if edge.start == *node {
+            &edge.end
+        } else {
+            &edge.start
+        }
+    }
+
+    fn connects(&self, edge: &Edge<T, U>, start: &T, end: &T) -> bool {
+        (edge.start == *start && edge.end == *end)
+            || (self.directedness == Directedness::Undirected
+                && edge.start == *end
+                && edge.end == *start)
+    }
+
+    fn incident_indices<'a>(&'a self, node: &T) -> impl Iterator<Item = usize> + 'a {
+        self.nodes.get(node).into_iter().flatten().copied()
+    }
+
+    fn incident_edges<'a>(&'a self, node: &T) -> impl Iterator<Item = &'a Edge<T, U>> + 'a {
+        self.incident_indices(node).map(|index| &self.edges[index])
+    }
+
+    fn endpoints(&self, index: usize) -> Vec<T> {
+        let Edge { start, end, .. } = &self.edges[index];
+        if start == end {
+            vec![start.clone()]
+        } else {
+            vec![start.clone(), end.clone()]
+        }
+    }
 
-        self.nodes.entry(edge.start).or_default().push(edge_index);
-        self.nodes.entry(edge.end).or_default().push(edge_index);
+    /// Records the edge at `index` against its endpoints
+    fn attach(&mut self, index: usize) {
+        for node in self.endpoints(index) {
+            self.nodes.entry(node).or_default().push(index);
+        }
+    }
+
+    /// Forgets the edge at `index` from its endpoints, removing nodes left
+    /// without edges
+    fn detach(&mut self, index: usize) {
+        for node in self.endpoints(index) {
+            if let Some(indices) = self.nodes.get_mut(&node) {
+                indices.retain(|&other| other != index);
+                if indices.is_empty() {
+                    self.nodes.remove(&node);
+                }
+            }
+        }
+    }
+
+    fn neighbor_list(&self, node: &T) -> Vec<T> {
+        self.neighbors(node).cloned().collect()
+    }
+}
+
+impl<T, C> Graph<T, C>
+where
+    T: Clone + Debug + Eq + Hash,
+    C: Copy + Ord + Default + std::ops::Add<Output = C> + std::ops::Sub<Output = C>,
+{
+    /// Pushes as much flow as possible from `source` to `sink` with the
+    /// Edmonds–Karp algorithm, treating each edge's data as its capacity. The
+    /// edges of an undirected graph carry flow either way. Returns `None` if
+    /// `source` and `sink` are the same or either is not in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent::utils::graph::{Edge, Graph};
+    ///
+    /// let pipes = Graph::from([
+    ///     Edge::with_data('s', 'a', 3),
+    ///     Edge::with_data('s', 'b', 2),
+    ///     Edge::with_data('a', 't', 1),
+    ///     Edge::with_data('b', 't', 4),
+    /// ]);
+    ///
+    /// let flow = pipes.max_flow(&'s', &'t').unwrap();
+    /// assert_eq!(flow.value, 3);
+    /// assert_eq!(flow.cut.edges, [Edge::with_data('s', 'b', 2), Edge::with_data('a', 't', 1)]);
+    /// assert_eq!((flow.cut.source_side, flow.cut.sink_side), (2, 2));
+    /// ```
+    pub fn max_flow(&self, source: &T, sink: &T) -> Option<flow::MaxFlow<T, C>> {
+        let nodes: Vec<T> = self.node_order().collect();
+        let indices: HashMap<&T, usize> = nodes.iter().enumerate().map(|(i, n)| (n, i)).collect();
+        let (source, sink) = (*indices.get(source)?, *indices.get(sink)?);
+        if source == sink {
+            return None;
+        }
+
+        let edges: Vec<_> = self
+            .edges
+            .iter()
+            .map(|edge| (indices[&edge.start], indices[&edge.end], edge.data))
+            .collect();
+
+        let undirected = self.directedness == Directedness::Undirected;
+        Some(flow::max_flow(&nodes, &edges, undirected, source, sink))
+    }
+}
+
+impl<T: Clone + Debug + Eq + Hash, U: Clone> Extend<Edge<T, U>> for Graph<T, U> {
+    fn extend<I: IntoIterator<Item = Edge<T, U>>>(&mut self, iter: I) {
+        for edge in iter {
+            self.add_edge(edge);
+        }
     }
 }
 
 impl<T: Clone + Debug + Eq + Hash, U: Clone, V: IntoIterator<Item = Edge<T, U>>> From<V>
     for Graph<T, U>
 {
-    /// Construct a `[Graph]` from an iterator over edges
+    /// Construct a directed `[Graph]` from an iterator over edges
     fn from(value: V) -> Self {
         let mut graph = Graph::new();
-
-        for item in value.into_iter() {
-            graph.add_edge(item);
-        }
-
+        graph.extend(value);
         graph
     }
 }
@@ -83,40 +369,182 @@ impl<T: Clone + Debug + Eq + Hash, U: Clone> Default for Graph<T, U> {
     }
 }
 
+/// Weighted edges between nodes that are interned as consecutive indices in
+/// the order they first appear.
 #[derive(Clone, Debug)]
 pub struct EdgeList<T, U> {
     nodes: Vec<T>,
+    indices: HashMap<T, usize>,
     edges: HashMap<(usize, usize), U>,
 }
 
+impl<T: Clone + Eq + Hash, U> EdgeList<T, U> {
+    /// Returns the node interned at `index`
+    pub fn node(&self, index: usize) -> Option<&T> {
+        self.nodes.get(index)
+    }
+
+    /// Returns the index at which `node` is interned
+    pub fn index_of(&self, node: &T) -> Option<usize> {
+        self.indices.get(node).copied()
+    }
+
+    /// Number of distinct nodes
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Iterates over the nodes in index order
+    pub fn nodes(&self) -> std::slice::Iter<'_, T> {
+        self.nodes.iter()
+    }
+
+    /// Partitions the nodes into strongly connected components in
+    /// topological order, following each edge from start to end. See
+    /// [`traversal::strongly_connected_components`].
+    pub fn strongly_connected_components(&self) -> Vec<Vec<&T>> {
+        let adjacency = self.adjacency();
+        traversal::strongly_connected_components(0..self.len(), |&node| adjacency[node].clone())
+            .into_iter()
+            .map(|component| component.into_iter().map(|n| &self.nodes[n]).collect())
+            .collect()
+    }
+
+    /// Collapses each strongly connected component into a single node of a
+    /// directed acyclic graph. See [`traversal::condensation`].
+    pub fn condensation(&self) -> traversal::Condensation<&T> {
+        let adjacency = self.adjacency();
+        let traversal::Condensation {
+            components,
+            component_of,
+            successors,
+        } = traversal::condensation(0..self.len(), |&node| adjacency[node].clone());
+
+        traversal::Condensation {
+            components: components
+                .into_iter()
+                .map(|component| component.into_iter().map(|n| &self.nodes[n]).collect())
+                .collect(),
+            component_of: component_of
+                .into_iter()
+                .map(|(n, component)| (&self.nodes[n], component))
+                .collect(),
+            successors,
+        }
+    }
+
+    /// Finds a cycle following each edge from start to end, listing its
+    /// nodes in order. See [`traversal::find_cycle`].
+    pub fn find_cycle(&self) -> Option<Vec<&T>> {
+        let adjacency = self.adjacency();
+        let cycle = traversal::find_cycle(0..self.len(), |&node| adjacency[node].clone())?;
+        Some(cycle.into_iter().map(|n| &self.nodes[n]).collect())
+    }
+
+    /// Describes the list in the Graphviz DOT language as a directed graph,
+    /// listing edges in order of their endpoints' indices. See [`dot::Dot`].
+    pub fn dot(&self) -> dot::Dot<'_, T, U>
+    where
+        T: Debug,
+    {
+        let edges = self
+            .edges
+            .iter()
+            .map(|(&(start, end), data)| (start, end, data))
+            .sorted_by_key(|&(start, end, _)| (start, end))
+            .collect();
+
+        dot::Dot::new(self.nodes.iter().collect(), edges, Directedness::Directed)
+    }
+
+    /// Indices of the nodes at the end of each node's edges, in index order
+    fn adjacency(&self) -> Vec<Vec<usize>> {
+        let mut adjacency = vec![Vec::new(); self.nodes.len()];
+        for &(start, end) in self.edges.keys() {
+            adjacency[start].push(end);
+        }
+        for targets in &mut adjacency {
+            targets.sort_unstable();
+        }
+
+        adjacency
+    }
+
+    fn intern(&mut self, node: T) -> usize {
+        if let Some(&index) = self.indices.get(&node) {
+            return index;
+        }
+
+        let index = self.nodes.len();
+        self.indices.insert(node.clone(), index);
+        self.nodes.push(node);
+        index
+    }
+}
+
 impl<T, U, V> From<V> for EdgeList<T, U>
 where
     T: Clone + Eq + Hash,
-    U: Clone,
-    V: Clone + IntoIterator<Item = Edge<T, U>>,
+    V: IntoIterator<Item = Edge<T, U>>,
 {
     fn from(value: V) -> Self {
-        let nodes = value
-            .clone()
-            .into_iter()
-            .flat_map(|edge| [edge.start, edge.end].into_iter())
-            .unique()
-            .collect::<Vec<_>>();
+        let mut list = Self {
+            nodes: Vec::new(),
+            indices: HashMap::new(),
+            edges: HashMap::new(),
+        };
 
-        let edges = value
-            .into_iter()
-            .map(|edge| {
-                (
-                    (
-                        nodes.iter().position(|x| *x == edge.start).unwrap(),
-                        nodes.iter().position(|x| *x == edge.end).unwrap(),
-                    ),
-                    edge.data,
-                )
-            })
+        for Edge { start, end, data } in value {
+            let key = (list.intern(start), list.intern(end));
+            list.edges.insert(key, data);
+        }
+
+        list
+    }
+}
+
+impl<T, C> EdgeList<T, C>
+where
+    T: Clone + Debug + Eq + Hash,
+    C: Copy + Ord + Default + std::ops::Add<Output = C> + std::ops::Sub<Output = C>,
+{
+    /// Pushes as much flow as possible from `source` to `sink` along each
+    /// edge from start to end, treating its weight as its capacity. See
+    /// [`Graph::max_flow`].
+    pub fn max_flow(&self, source: &T, sink: &T) -> Option<flow::MaxFlow<T, C>> {
+        let (source, sink) = (self.index_of(source)?, self.index_of(sink)?);
+        if source == sink {
+            return None;
+        }
+
+        let edges: Vec<_> = self
+            .edges
+            .iter()
+            .map(|(&(start, end), &capacity)| (start, end, capacity))
+            .sorted_by_key(|&(start, end, _)| (start, end))
             .collect();
 
-        Self { nodes, edges }
+        Some(flow::max_flow(&self.nodes, &edges, false, source, sink))
+    }
+}
+
+/// Whether a tour search minimizes or maximizes the total weight
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Objective {
+    Shortest,
+    Longest,
+}
+
+impl Objective {
+    fn prefers<W: Ord>(self, candidate: W, incumbent: W) -> bool {
+        match self {
+            Self::Shortest => candidate < incumbent,
+            Self::Longest => candidate > incumbent,
+        }
     }
 }
 
@@ -124,28 +552,165 @@ impl<T, Weight> EdgeList<T, Weight>
 where
     Weight: Copy + Ord + Default + std::ops::Add<Output = Weight>,
 {
-    fn shortest_tour_impl<V>(
+    fn best_named_tour(
         &self,
         dist: fn(Option<Weight>, Option<Weight>) -> Option<Weight>,
-        tours: V,
-    ) -> (Vec<usize>, Weight)
-    where
-        V: IntoIterator<Item = Vec<usize>>,
-    {
-        tours
-            .into_iter()
-            .filter_map(|tour| {
-                tour.iter()
-                    .tuple_windows()
-                    .try_fold(Weight::default(), |acc, (&start, &end)| {
+        cyclic: bool,
+        objective: Objective,
+    ) -> Option<(Vec<&T>, Weight)> {
+        let (tour, weight) = self.best_tour(dist, cyclic, objective)?;
+        Some((
+            tour.into_iter().map(|index| &self.nodes[index]).collect(),
+            weight,
+        ))
+    }
+
+    /// Finds the best tour visiting every node exactly once with the
+    /// Held–Karp algorithm, which takes `O(n² 2ⁿ)` time rather than the
+    /// `O(n!)` of checking every permutation. Cyclic tours start and end at
+    /// node `0`; acyclic tours may start anywhere.
+    ///
+    /// `best[visited][last]` holds the best weight of a path through the
+    /// nodes in the bitmask `visited` that ends at `last`, together with the
+    /// node preceding `last` on that path. Returns `None` if `dist` leaves
+    /// no tour through every node, including when there are no nodes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are [`usize::BITS`] or more nodes, which cannot be
+    /// represented in a bitmask.
+    fn best_tour(
+        &self,
+        dist: fn(Option<Weight>, Option<Weight>) -> Option<Weight>,
+        cyclic: bool,
+        objective: Objective,
+    ) -> Option<(Vec<usize>, Weight)> {
+        let n = self.nodes.len();
+        assert!(
+            n < usize::BITS as usize,
+            "tours through {} nodes are too large to search",
+            n
+        );
+        if n == 0 {
+            return None;
+        }
+
+        let weights = (0..n)
+            .map(|start| {
+                (0..n)
+                    .map(|end| {
                         let fwd = self.edges.get(&(start, end));
                         let bkwd = self.edges.get(&(end, start));
-                        dist(fwd.copied(), bkwd.copied()).map(|weight| acc + weight)
+                        dist(fwd.copied(), bkwd.copied())
                     })
-                    .map(|tour_length| (tour, tour_length))
+                    .collect::<Vec<_>>()
             })
-            .min_by_key(|(_, tour_length)| *tour_length)
-            .unwrap()
+            .collect::<Vec<_>>();
+
+        let full = (1_usize << n) - 1;
+        let mut best: Vec<Vec<Option<(Weight, usize)>>> = vec![vec![None; n]; full + 1];
+        let starts = if cyclic { 0..n.min(1) } else { 0..n };
+        for start in starts {
+            best[1 << start][start] = Some((Weight::default(), start));
+        }
+
+        for visited in 1..=full {
+            for last in 0..n {
+                let Some((weight, _)) = best[visited][last] else {
+                    continue;
+                };
+
+                for next in (0..n).filter(|next| visited & (1 << next) == 0) {
+                    let Some(step) = weights[last][next] else {
+                        continue;
+                    };
+
+                    let candidate = weight + step;
+                    let slot = &mut best[visited | (1 << next)][next];
+                    if slot.is_none_or(|(incumbent, _)| objective.prefers(candidate, incumbent)) {
+                        *slot = Some((candidate, last));
+                    }
+                }
+            }
+        }
+
+        let (mut last, total) = (0..n)
+            .filter_map(|last| {
+                let (weight, _) = best[full][last]?;
+                if cyclic {
+                    weights[last][0].map(|step| (last, weight + step))
+                } else {
+                    Some((last, weight))
+                }
+            })
+            .reduce(|incumbent, candidate| {
+                if objective.prefers(candidate.1, incumbent.1) {
+                    candidate
+                } else {
+                    incumbent
+                }
+            })?;
+
+        let mut tour = vec![last];
+        let mut visited = full;
+        while visited.count_ones() > 1 {
+            let (_, previous) = best[visited][last].expect("every step of a tour is recorded");
+            visited ^= 1 << last;
+            last = previous;
+            tour.push(last);
+        }
+
+        tour.reverse();
+        if cyclic {
+            tour.push(0);
+        }
+
+        Some((tour, total))
+    }
+
+    /// Finds a minimum spanning tree with Kruskal's algorithm, treating every
+    /// edge as undirected, and returns its edges along with their total
+    /// weight. A disconnected graph yields a minimum spanning forest. Edges of
+    /// equal weight are considered in order of their endpoints' indices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent::utils::graph::{Edge, EdgeList};
+    ///
+    /// let list = EdgeList::from([
+    ///     Edge::with_data('a', 'b', 4),
+    ///     Edge::with_data('b', 'c', 1),
+    ///     Edge::with_data('c', 'a', 2),
+    /// ]);
+    ///
+    /// let (edges, weight) = list.minimum_spanning_tree();
+    /// assert_eq!(edges, [Edge::with_data(&'b', &'c', 1), Edge::with_data(&'c', &'a', 2)]);
+    /// assert_eq!(weight, 3);
+    /// ```
+    pub fn minimum_spanning_tree(&self) -> (Vec<Edge<&T, Weight>>, Weight) {
+        let mut sets = union_find::UnionFind::new(self.nodes.len());
+        let mut total = Weight::default();
+        let mut tree = Vec::new();
+
+        let edges = self
+            .edges
+            .iter()
+            .map(|(&(start, end), &weight)| (weight, start, end))
+            .sorted();
+
+        for (weight, start, end) in edges {
+            if sets.union(start, end) {
+                total = total + weight;
+                tree.push(Edge::with_data(
+                    &self.nodes[start],
+                    &self.nodes[end],
+                    weight,
+                ));
+            }
+        }
+
+        (tree, total)
     }
 
     /// Finds the shortest cyclic tour (i.e. start == end) through the graph,
@@ -154,20 +719,20 @@ where
     /// case for directed graphs where the forward distance does not equal the
     /// reverse distance.
     ///
-    /// Assumes the tour starts and ends at `nodes.front()`.
+    /// Assumes the tour starts and ends at the first node added. Returns
+    /// `None` if no tour visits every node, such as when `dist` leaves the
+    /// graph disconnected or the graph is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graph has [`usize::BITS`] or more nodes. Every tour
+    /// method shares this limit, though far fewer nodes already exhaust
+    /// memory.
     pub fn shortest_cyclic_tour_by(
         &self,
         dist: fn(forward_weight: Option<Weight>, backward_weight: Option<Weight>) -> Option<Weight>,
-    ) -> (Vec<usize>, Weight) {
-        let indices = (1..self.nodes.len())
-            .permutations(self.nodes.len() - 1)
-            .map(|v| {
-                std::iter::once(0)
-                    .chain(v)
-                    .chain(std::iter::once(0))
-                    .collect()
-            });
-        self.shortest_tour_impl(dist, indices)
+    ) -> Option<(Vec<&T>, Weight)> {
+        self.best_named_tour(dist, true, Objective::Shortest)
     }
 
     /// Finds the shortest acyclic tour (i.e. start != end) through the graph,
@@ -175,25 +740,57 @@ where
     /// accumulation of `dist` over all edges. This accommodates the general
     /// case for directed graphs where the forward distance does not equal the
     /// reverse distance.
+    ///
+    /// Returns `None` if no tour visits every node, and panics on as many
+    /// nodes as [`EdgeList::shortest_cyclic_tour_by`].
     pub fn shortest_acyclic_tour_by(
         &self,
         dist: fn(Option<Weight>, Option<Weight>) -> Option<Weight>,
-    ) -> (Vec<usize>, Weight) {
-        let indices = (0..self.nodes.len()).permutations(self.nodes.len());
-        self.shortest_tour_impl(dist, indices)
+    ) -> Option<(Vec<&T>, Weight)> {
+        self.best_named_tour(dist, false, Objective::Shortest)
+    }
+
+    /// Finds the longest cyclic tour through the graph, evaluating `dist` for
+    /// every edge as in [`EdgeList::shortest_cyclic_tour_by`].
+    pub fn longest_cyclic_tour_by(
+        &self,
+        dist: fn(Option<Weight>, Option<Weight>) -> Option<Weight>,
+    ) -> Option<(Vec<&T>, Weight)> {
+        self.best_named_tour(dist, true, Objective::Longest)
+    }
+
+    /// Finds the longest acyclic tour through the graph, evaluating `dist`
+    /// for every edge as in [`EdgeList::shortest_acyclic_tour_by`].
+    pub fn longest_acyclic_tour_by(
+        &self,
+        dist: fn(Option<Weight>, Option<Weight>) -> Option<Weight>,
+    ) -> Option<(Vec<&T>, Weight)> {
+        self.best_named_tour(dist, false, Objective::Longest)
     }
 
     /// Finds the shortest cyclic tour of an undirected graph by accumulating
     /// the forward distance for each pair of nodes
-    pub fn shortest_cyclic_tour(&self) -> (Vec<usize>, Weight) {
+    pub fn shortest_cyclic_tour(&self) -> Option<(Vec<&T>, Weight)> {
         self.shortest_cyclic_tour_by(|p, _| p)
     }
 
     /// Finds the shortest acyclic tour of an undirected graph by accumulating
     /// the forward distance for each pair of nodes
-    pub fn shortest_acyclic_tour(&self) -> (Vec<usize>, Weight) {
+    pub fn shortest_acyclic_tour(&self) -> Option<(Vec<&T>, Weight)> {
         self.shortest_acyclic_tour_by(|p, _| p)
     }
+
+    /// Finds the longest cyclic tour of an undirected graph by accumulating
+    /// the forward distance for each pair of nodes
+    pub fn longest_cyclic_tour(&self) -> Option<(Vec<&T>, Weight)> {
+        self.longest_cyclic_tour_by(|p, _| p)
+    }
+
+    /// Finds the longest acyclic tour of an undirected graph by accumulating
+    /// the forward distance for each pair of nodes
+    pub fn longest_acyclic_tour(&self) -> Option<(Vec<&T>, Weight)> {
+        self.longest_acyclic_tour_by(|p, _| p)
+    }
 }
 
 #[cfg(test)]
@@ -221,4 +818,327 @@ mod tests {
         assert_eq!(graph.nodes.get(&1), Some(&vec![0, 1]));
         assert_eq!(graph.nodes.get(&2), Some(&vec![1, 2]));
     }
+
+    #[test]
+    fn traversals() {
+        let edges = vec![
+            Edge::new('a', 'b'),
+            Edge::new('a', 'c'),
+            Edge::new('c', 'b'),
+            Edge::new('b', 'd'),
+        ];
+        let graph = Graph::from(edges);
+
+        assert_eq!(graph.neighbors(&'a').collect::<String>(), "bc");
+        assert_eq!(graph.predecessors(&'b').collect::<String>(), "ac");
+        assert_eq!(
+            graph.bfs(&'a'),
+            vec![('a', 0), ('b', 1), ('c', 1), ('d', 2)]
+        );
+        assert_eq!(graph.dfs(&'c').postorder, vec!['d', 'b', 'c']);
+        assert_eq!(graph.reachable(&'b'), HashSet::from(['b', 'd']));
+        assert_eq!(graph.topological_sort(), Ok(vec!['a', 'c', 'b', 'd']));
+
+        let mut cyclic = graph.clone();
+        cyclic.add_edge(Edge::new('d', 'c'));
+        assert_eq!(
+            cyclic.topological_sort(),
+            Err(traversal::CycleError(vec!['b', 'c', 'd']))
+        );
+    }
+
+    #[test]
+    fn directedness() {
+        let edges = vec![
+            Edge::new('a', 'b'),
+            Edge::new('c', 'a'),
+            Edge::new('b', 'c'),
+            Edge::new('c', 'c'),
+        ];
+        let directed = Graph::from(edges.clone());
+        let mut undirected = Graph::undirected();
+        undirected.extend(edges);
+
+        assert_eq!(directed.directedness(), Directedness::Directed);
+        assert_eq!(directed.neighbors(&'a').collect::<String>(), "b");
+        assert_eq!(directed.predecessors(&'a').collect::<String>(), "c");
+        assert_eq!(directed.neighbors(&'c').collect::<String>(), "ac");
+        assert_eq!(
+            (directed.out_degree(&'c'), directed.in_degree(&'c')),
+            (2, 2)
+        );
+
+        assert_eq!(undirected.neighbors(&'a').collect::<String>(), "bc");
+        assert_eq!(undirected.predecessors(&'b').collect::<String>(), "ac");
+        assert_eq!(undirected.degree(&'c'), 3);
+        assert_eq!(undirected.out_edges(&'c').count(), 3);
+        assert!(undirected.topological_sort().is_err());
+    }
+
+    #[test]
+    fn remove_edges() {
+        let mut graph = Graph::undirected();
+        graph.extend([Edge::new(0, 1), Edge::new(1, 2), Edge::new(2, 3)]);
+
+        assert_eq!(graph.remove_edge(&2, &1), Some(Edge::new(1, 2)));
+        assert_eq!(graph.remove_edge(&2, &1), None);
+        assert_eq!(graph.edges, vec![Edge::new(0, 1), Edge::new(2, 3)]);
+        assert_eq!(graph.nodes.get(&2), Some(&vec![1]));
+        assert_eq!(graph.neighbors(&3).collect::<Vec<_>>(), vec![&2]);
+        assert_eq!(graph.reachable(&0), HashSet::from([0, 1]));
+
+        let mut directed = Graph::from([Edge::new(0, 1)]);
+        assert_eq!(directed.remove_edge(&1, &0), None);
+        assert_eq!(directed.remove_edge(&0, &1), Some(Edge::new(0, 1)));
+        assert!(directed.nodes.is_empty());
+    }
+
+    #[test]
+    fn induced_subgraphs() {
+        let mut graph = Graph::undirected();
+        graph.extend([
+            Edge::with_data("a", "b", 1),
+            Edge::with_data("b", "c", 2),
+            Edge::with_data("c", "a", 3),
+            Edge::with_data("c", "d", 4),
+        ]);
+
+        let subgraph = graph.induced_subgraph(|&node| node != "c");
+        assert_eq!(subgraph.edges, vec![Edge::with_data("a", "b", 1)]);
+        assert_eq!(subgraph.directedness(), Directedness::Undirected);
+        assert_eq!(subgraph.degree(&"c"), 0);
+        assert_eq!(graph.induced_subgraph(|_| true).edges, graph.edges);
+    }
+
+    #[test]
+    fn strong_components() {
+        let graph = Graph::from([
+            Edge::new('a', 'b'),
+            Edge::new('b', 'c'),
+            Edge::new('c', 'a'),
+            Edge::new('c', 'd'),
+            Edge::new('d', 'e'),
+            Edge::new('e', 'd'),
+        ]);
+
+        assert_eq!(
+            graph.strongly_connected_components(),
+            vec![vec!['a', 'b', 'c'], vec!['d', 'e']]
+        );
+        assert_eq!(graph.condensation().successors, vec![vec![1], vec![]]);
+        assert_eq!(graph.find_cycle(), Some(vec!['a', 'b', 'c']));
+
+        let dag = graph.induced_subgraph(|&node| node != 'c' && node != 'e');
+        assert_eq!(dag.find_cycle(), None);
+        assert_eq!(dag.condensation().components, vec![vec!['a'], vec!['b']]);
+
+        let mut undirected = Graph::undirected();
+        undirected.extend([Edge::new(0, 1), Edge::new(1, 2)]);
+        assert_eq!(
+            undirected.strongly_connected_components(),
+            vec![vec![0, 1, 2]]
+        );
+        assert_eq!(undirected.find_cycle(), Some(vec![0, 1]));
+    }
+
+    #[test]
+    fn edge_list_components() {
+        let list = EdgeList::from([
+            Edge::with_data("x", "y", 1),
+            Edge::with_data("y", "z", 2),
+            Edge::with_data("z", "y", 3),
+        ]);
+
+        assert_eq!(
+            list.strongly_connected_components(),
+            vec![vec![&"x"], vec![&"y", &"z"]]
+        );
+        assert_eq!(list.find_cycle(), Some(vec![&"y", &"z"]));
+
+        let condensation = list.condensation();
+        assert_eq!(condensation.component_of[&"z"], 1);
+        assert_eq!(condensation.successors, vec![vec![1], vec![]]);
+
+        let chain = EdgeList::from([Edge::with_data(0, 1, ()), Edge::with_data(1, 2, ())]);
+        assert_eq!(chain.find_cycle(), None);
+    }
+
+    #[test]
+    fn spanning_trees() {
+        let graph = asymmetric();
+        let (tree, weight) = graph.minimum_spanning_tree();
+        assert_eq!(weight, 7);
+        assert_eq!(tree.len(), 4);
+
+        // Two triangles, each with one expensive edge, and an isolated pair
+        let forest = EdgeList::from([
+            Edge::with_data("a", "b", 1),
+            Edge::with_data("b", "c", 1),
+            Edge::with_data("c", "a", 5),
+            Edge::with_data("x", "y", 2),
+            Edge::with_data("y", "z", 9),
+            Edge::with_data("z", "x", 3),
+            Edge::with_data("p", "q", 7),
+        ]);
+
+        let (edges, weight) = forest.minimum_spanning_tree();
+        assert_eq!(weight, 14);
+        assert_eq!(
+            edges
+                .iter()
+                .map(|edge| (*edge.start, *edge.end))
+                .collect::<Vec<_>>(),
+            [("a", "b"), ("b", "c"), ("x", "y"), ("z", "x"), ("p", "q")]
+        );
+    }
+
+    #[test]
+    fn flows() {
+        let mut graph = Graph::undirected();
+        graph.extend([
+            Edge::with_data("s", "a", 2),
+            Edge::with_data("a", "b", 5),
+            Edge::with_data("b", "s", 3),
+            Edge::with_data("b", "t", 4),
+            Edge::with_data("t", "a", 2),
+        ]);
+
+        let flow = graph.max_flow(&"s", &"t").unwrap();
+        assert_eq!(flow.value, 5);
+        assert_eq!(flow.cut.source_side, 1);
+        assert_eq!(flow.residual.directedness(), Directedness::Directed);
+        assert!(graph.max_flow(&"s", &"s").is_none());
+        assert!(graph.max_flow(&"s", &"x").is_none());
+
+        let list = EdgeList::from(graph.edges.clone());
+        let flow = list.max_flow(&"s", &"t").unwrap();
+        assert_eq!(flow.value, 2);
+        assert_eq!(flow.cut.edges, [Edge::with_data("s", "a", 2)]);
+        assert_eq!((flow.cut.source_side, flow.cut.sink_side), (1, 3));
+    }
+
+    /// Asymmetric weights between five nodes, where `WEIGHTS[i][j]` is the
+    /// weight of the edge from `i` to `j`
+    const WEIGHTS: [[i32; 5]; 5] = [
+        [0, 3, 9, 4, 7],
+        [5, 0, 2, 8, 1],
+        [6, 4, 0, 3, 9],
+        [2, 7, 5, 0, 6],
+        [8, 1, 4, 2, 0],
+    ];
+
+    fn asymmetric() -> EdgeList<usize, i32> {
+        EdgeList::from(
+            (0..5)
+                .cartesian_product(0..5)
+                .filter(|(i, j)| i != j)
+                .map(|(i, j)| Edge::with_data(i, j, WEIGHTS[i][j]))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Weight of `tour` through [`asymmetric`], whose nodes are numbered in
+    /// the order they first appear
+    fn tour_weight<'a, I: IntoIterator<Item = &'a usize>>(tour: I) -> i32 {
+        tour.into_iter()
+            .tuple_windows()
+            .map(|(&start, &end)| WEIGHTS[start][end])
+            .sum()
+    }
+
+    #[test]
+    fn tours_match_exhaustive_search() {
+        let graph = asymmetric();
+        let cyclic = (1..5).permutations(4).map(|middle| {
+            let tour = [vec![0], middle, vec![0]].concat();
+            tour_weight(&tour)
+        });
+        let acyclic = (0..5).permutations(5).map(|tour| tour_weight(&tour));
+
+        let (tour, weight) = graph.shortest_cyclic_tour().unwrap();
+        assert_eq!((tour.first(), tour.last()), (Some(&&0), Some(&&0)));
+        assert_eq!(tour_weight(tour.iter().copied()), weight);
+        assert_eq!(Some(weight), cyclic.clone().min());
+
+        let (tour, weight) = graph.longest_cyclic_tour().unwrap();
+        assert_eq!(tour_weight(tour.iter().copied()), weight);
+        assert_eq!(Some(weight), cyclic.max());
+
+        let (tour, weight) = graph.shortest_acyclic_tour().unwrap();
+        assert_eq!(tour.iter().unique().count(), 5);
+        assert_eq!(tour_weight(tour.iter().copied()), weight);
+        assert_eq!(Some(weight), acyclic.clone().min());
+
+        let (_, weight) = graph.longest_acyclic_tour().unwrap();
+        assert_eq!(Some(weight), acyclic.max());
+    }
+
+    #[test]
+    fn tours_with_asymmetric_dist() {
+        let graph = asymmetric();
+        let round_trip = |fwd: Option<i32>, bkwd: Option<i32>| Some(fwd? + bkwd?);
+
+        let (tour, weight) = graph.shortest_cyclic_tour_by(round_trip).unwrap();
+        assert_eq!(
+            weight,
+            tour_weight(tour.iter().copied()) + tour_weight(tour.iter().rev().copied())
+        );
+    }
+
+    #[test]
+    fn tours_of_many_nodes() {
+        // Nodes on a line, so the shortest open tour walks it end to end
+        let graph = EdgeList::from(
+            (0..14_i32)
+                .tuple_combinations()
+                .map(|(a, b)| Edge::with_data(a, b, b - a))
+                .collect::<Vec<_>>(),
+        );
+
+        let undirected = |fwd: Option<i32>, bkwd: Option<i32>| fwd.or(bkwd);
+        assert_eq!(graph.shortest_acyclic_tour_by(undirected).unwrap().1, 13);
+        assert_eq!(graph.shortest_cyclic_tour_by(undirected).unwrap().1, 26);
+    }
+
+    #[test]
+    fn missing_tours() {
+        let empty: EdgeList<char, i32> = EdgeList::from(Vec::new());
+        assert_eq!(empty.shortest_acyclic_tour(), None);
+        assert_eq!(empty.longest_cyclic_tour(), None);
+
+        // Two separate pairs, which no single path can join
+        let disconnected =
+            EdgeList::from([Edge::with_data('a', 'b', 1), Edge::with_data('c', 'd', 1)]);
+        let undirected = |fwd: Option<i32>, bkwd: Option<i32>| fwd.or(bkwd);
+        assert_eq!(disconnected.shortest_acyclic_tour_by(undirected), None);
+        assert_eq!(disconnected.longest_cyclic_tour_by(undirected), None);
+
+        // A lone node has no edge back to itself to close a cycle
+        let single = EdgeList::from([Edge::with_data('a', 'a', 1)]);
+        assert_eq!(single.shortest_acyclic_tour(), Some((vec![&'a'], 0)));
+        assert_eq!(single.shortest_cyclic_tour_by(|_, _| None), None);
+    }
+
+    #[test]
+    fn interned_nodes() {
+        let graph = EdgeList::from([
+            Edge::with_data("London", "Dublin", 464),
+            Edge::with_data("London", "Belfast", 518),
+            Edge::with_data("Dublin", "Belfast", 141),
+        ]);
+
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.node(2), Some(&"Belfast"));
+        assert_eq!(graph.node(3), None);
+        assert_eq!(graph.index_of(&"Dublin"), Some(1));
+        assert_eq!(graph.index_of(&"Paris"), None);
+        assert_eq!(
+            graph.nodes().copied().collect::<Vec<_>>(),
+            ["London", "Dublin", "Belfast"]
+        );
+
+        let (tour, distance) = graph.shortest_acyclic_tour().unwrap();
+        assert_eq!(tour, [&"London", &"Dublin", &"Belfast"]);
+        assert_eq!(distance, 605);
+    }
 }
@@ -0,0 +1,565 @@
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Nodes that could not be placed in a topological order because they lie on,
+/// or can only be reached through, a cycle.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("graph contains a cycle; {} nodes could not be ordered", .0.len())]
+pub struct CycleError<T: Debug>(pub Vec<T>);
+
+/// Order in which a depth-first search discovered and finished each node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DepthFirst<T> {
+    /// Nodes in the order they were first visited
+    pub preorder: Vec<T>,
+    /// Nodes in the order all of their descendants were finished
+    pub postorder: Vec<T>,
+}
+
+/// Visits every node reachable from `start` in breadth-first order, pairing
+/// each with the number of edges on the shortest path from `start`.
+///
+/// # Examples
+///
+/// ```
+/// use advent::utils::graph::traversal;
+///
+/// // Halve or decrement until reaching zero
+/// let visits = traversal::bfs(10, |&n: &u32| [n / 2, n.saturating_sub(1)]);
+/// assert_eq!(visits.first(), Some(&(10, 0)));
+/// assert!(visits.contains(&(0, 4)));
+/// ```
+pub fn bfs<T, I, F>(start: T, mut neighbors: F) -> Vec<(T, usize)>
+where
+    T: Clone + Eq + Hash,
+    I: IntoIterator<Item = T>,
+    F: FnMut(&T) -> I,
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    let mut visits = Vec::new();
+
+    while let Some((node, distance)) = queue.pop_front() {
+        for neighbor in neighbors(&node) {
+            if seen.insert(neighbor.clone()) {
+                queue.push_back((neighbor, distance + 1));
+            }
+        }
+
+        visits.push((node, distance));
+    }
+
+    visits
+}
+
+/// Explores every node reachable from `start` depth-first without recursion,
+/// following neighbors in the order `neighbors` yields them.
+pub fn dfs<T, I, F>(start: T, mut neighbors: F) -> DepthFirst<T>
+where
+    T: Clone + Eq + Hash,
+    I: IntoIterator<Item = T>,
+    F: FnMut(&T) -> I,
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut preorder = vec![start.clone()];
+    let mut postorder = Vec::new();
+
+    let children = neighbors(&start).into_iter();
+    let mut stack = vec![(start, children)];
+
+    while let Some((_, children)) = stack.last_mut() {
+        match children.find(|child| !seen.contains(child)) {
+            Some(child) => {
+                seen.insert(child.clone());
+                preorder.push(child.clone());
+
+                let grandchildren = neighbors(&child).into_iter();
+                stack.push((child, grandchildren));
+            }
+            None => {
+                if let Some((node, _)) = stack.pop() {
+                    postorder.push(node);
+                }
+            }
+        }
+    }
+
+    DepthFirst {
+        preorder,
+        postorder,
+    }
+}
+
+/// Collects every node reachable from `start`, including `start` itself.
+pub fn reachable<T, I, F>(start: T, neighbors: F) -> HashSet<T>
+where
+    T: Clone + Eq + Hash,
+    I: IntoIterator<Item = T>,
+    F: FnMut(&T) -> I,
+{
+    bfs(start, neighbors)
+        .into_iter()
+        .map(|(node, _)| node)
+        .collect()
+}
+
+/// Orders `nodes`, and any nodes reachable from them, so that every node
+/// precedes its `successors`, using Kahn's algorithm. Ties are broken by the
+/// order in which nodes were first encountered.
+///
+/// # Examples
+///
+/// ```
+/// use advent::utils::graph::traversal::{self, CycleError};
+///
+/// let order = traversal::topological_sort([3, 1], |&n: &u32| (n > 1).then(|| n - 1));
+/// assert_eq!(order, Ok(vec![3, 2, 1]));
+///
+/// let cycle = traversal::topological_sort([0], |&n: &u32| Some((n + 1) % 3));
+/// assert_eq!(cycle, Err(CycleError(vec![0, 1, 2])));
+/// ```
+pub fn topological_sort<T, N, I, F>(nodes: N, successors: F) -> Result<Vec<T>, CycleError<T>>
+where
+    T: Clone + Debug + Eq + Hash,
+    N: IntoIterator<Item = T>,
+    I: IntoIterator<Item = T>,
+    F: FnMut(&T) -> I,
+{
+    let (nodes, edges) = index_graph(nodes, successors);
+
+    let mut in_degree = vec![0; nodes.len()];
+    for &target in edges.iter().flatten() {
+        in_degree[target] += 1;
+    }
+
+    let mut queue: VecDeque<usize> = (0..nodes.len()).filter(|&n| in_degree[n] == 0).collect();
+    let mut order = Vec::with_capacity(nodes.len());
+    while let Some(node) = queue.pop_front() {
+        order.push(node);
+        for &target in &edges[node] {
+            in_degree[target] -= 1;
+            if in_degree[target] == 0 {
+                queue.push_back(target);
+            }
+        }
+    }
+
+    if order.len() < nodes.len() {
+        let blocked = (0..nodes.len())
+            .filter(|&n| in_degree[n] > 0)
+            .map(|n| nodes[n].clone())
+            .collect();
+        return Err(CycleError(blocked));
+    }
+
+    Ok(order.into_iter().map(|n| nodes[n].clone()).collect())
+}
+
+/// Strongly connected components of a directed graph, collapsed into the
+/// nodes of a directed acyclic graph.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Condensation<T: Eq + Hash> {
+    /// Components in topological order, so every edge between components
+    /// leads to a later one. Each component lists its nodes in the order they
+    /// were discovered.
+    pub components: Vec<Vec<T>>,
+    /// Index into `components` of the component holding each node
+    pub component_of: HashMap<T, usize>,
+    /// Sorted indices of the components one edge away from each component
+    pub successors: Vec<Vec<usize>>,
+}
+
+/// Partitions `nodes`, and any nodes reachable from them, into strongly
+/// connected components with Tarjan's algorithm. Components are returned in
+/// topological order, so no edge leads from a component to an earlier one.
+///
+/// # Examples
+///
+/// ```
+/// use advent::utils::graph::traversal;
+///
+/// // 0 ⇄ 1 → 2 ⇄ 3
+/// let successors = |&n: &u32| match n {
+///     0 => vec![1],
+///     1 => vec![0, 2],
+///     2 => vec![3],
+///     _ => vec![2],
+/// };
+///
+/// let components = traversal::strongly_connected_components([0], successors);
+/// assert_eq!(components, vec![vec![0, 1], vec![2, 3]]);
+/// ```
+pub fn strongly_connected_components<T, N, I, F>(nodes: N, successors: F) -> Vec<Vec<T>>
+where
+    T: Clone + Eq + Hash,
+    N: IntoIterator<Item = T>,
+    I: IntoIterator<Item = T>,
+    F: FnMut(&T) -> I,
+{
+    let (nodes, edges) = index_graph(nodes, successors);
+
+    tarjan(&edges)
+        .into_iter()
+        .map(|component| component.into_iter().map(|n| nodes[n].clone()).collect())
+        .collect()
+}
+
+/// Collapses each strongly connected component of the graph reachable from
+/// `nodes` into a single node. See [`strongly_connected_components`].
+pub fn condensation<T, N, I, F>(nodes: N, successors: F) -> Condensation<T>
+where
+    T: Clone + Eq + Hash,
+    N: IntoIterator<Item = T>,
+    I: IntoIterator<Item = T>,
+    F: FnMut(&T) -> I,
+{
+    let (nodes, edges) = index_graph(nodes, successors);
+    let components = tarjan(&edges);
+
+    let membership = membership(&components, nodes.len());
+
+    let successors = components
+        .iter()
+        .enumerate()
+        .map(|(index, component)| {
+            component
+                .iter()
+                .flat_map(|&node| &edges[node])
+                .map(|&target| membership[target])
+                .filter(|&target| target != index)
+                .sorted()
+                .dedup()
+                .collect()
+        })
+        .collect();
+
+    Condensation {
+        component_of: nodes.iter().cloned().zip(membership).collect(),
+        components: components
+            .into_iter()
+            .map(|component| component.into_iter().map(|n| nodes[n].clone()).collect())
+            .collect(),
+        successors,
+    }
+}
+
+/// Finds a cycle among `nodes` and the nodes reachable from them, returning
+/// its nodes in order such that each has an edge to the next and the last
+/// has an edge back to the first. The cycle is a shortest one through the
+/// first node discovered in the earliest cyclic component.
+///
+/// # Examples
+///
+/// ```
+/// use advent::utils::graph::traversal;
+///
+/// // 1 → 2 ⇄ 4
+/// let cycle = traversal::find_cycle([1], |&n: &u32| [n * 2 % 6]);
+/// assert_eq!(cycle, Some(vec![2, 4]));
+///
+/// let acyclic = traversal::find_cycle([3], |&n: &u32| n.checked_sub(1));
+/// assert_eq!(acyclic, None);
+/// ```
+pub fn find_cycle<T, N, I, F>(nodes: N, successors: F) -> Option<Vec<T>>
+where
+    T: Clone + Eq + Hash,
+    N: IntoIterator<Item = T>,
+    I: IntoIterator<Item = T>,
+    F: FnMut(&T) -> I,
+{
+    let (nodes, edges) = index_graph(nodes, successors);
+    let components = tarjan(&edges);
+
+    let membership = membership(&components, nodes.len());
+
+    let cyclic = components.iter().enumerate().find(|(_, component)| {
+        component.len() > 1 || edges[component[0]].contains(&component[0])
+    })?;
+    let (index, component) = cyclic;
+    let start = component[0];
+
+    // Breadth-first search within the component for the shortest way back
+    let mut parents = HashMap::new();
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        for &target in &edges[node] {
+            if membership[target] != index || parents.contains_key(&target) {
+                continue;
+            }
+
+            parents.insert(target, node);
+            if target == start {
+                queue.clear();
+                break;
+            }
+            queue.push_back(target);
+        }
+    }
+
+    let mut cycle = Vec::new();
+    let mut node = parents[&start];
+    while node != start {
+        cycle.push(nodes[node].clone());
+        node = parents[&node];
+    }
+    cycle.push(nodes[start].clone());
+    cycle.reverse();
+
+    Some(cycle)
+}
+
+/// Interns `nodes`, and any nodes reachable from them, as consecutive indices
+/// in the order they are encountered, listing the successors of each index.
+fn index_graph<T, N, I, F>(nodes: N, mut successors: F) -> (Vec<T>, Vec<Vec<usize>>)
+where
+    T: Clone + Eq + Hash,
+    N: IntoIterator<Item = T>,
+    I: IntoIterator<Item = T>,
+    F: FnMut(&T) -> I,
+{
+    let mut nodes: Vec<T> = nodes.into_iter().collect();
+    let mut indices: HashMap<T, usize> = HashMap::new();
+    nodes.retain(|node| {
+        let index = indices.len();
+        indices.insert(node.clone(), index).is_none()
+    });
+
+    let mut edges = Vec::new();
+    let mut i = 0;
+    while i < nodes.len() {
+        let mut targets = Vec::new();
+        for successor in successors(&nodes[i]) {
+            let index = *indices.entry(successor.clone()).or_insert_with(|| {
+                nodes.push(successor);
+                nodes.len() - 1
+            });
+            targets.push(index);
+        }
+
+        edges.push(targets);
+        i += 1;
+    }
+
+    (nodes, edges)
+}
+
+/// Tarjan's strongly connected components algorithm over nodes numbered from
+/// zero, without recursion. Components are returned in topological order.
+fn tarjan(edges: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let n = edges.len();
+    let mut discovered: Vec<Option<usize>> = vec![None; n];
+    let mut lowlink = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut components = Vec::new();
+    let mut counter = 0;
+
+    for root in 0..n {
+        if discovered[root].is_some() {
+            continue;
+        }
+
+        // Each frame holds a node and how many of its edges have been followed
+        let mut frames = vec![(root, 0)];
+        discovered[root] = Some(counter);
+        lowlink[root] = counter;
+        counter += 1;
+        stack.push(root);
+        on_stack[root] = true;
+
+        while let Some((node, followed)) = frames.last_mut() {
+            let node = *node;
+            if let Some(&target) = edges[node].get(*followed) {
+                *followed += 1;
+                match discovered[target] {
+                    None => {
+                        discovered[target] = Some(counter);
+                        lowlink[target] = counter;
+                        counter += 1;
+                        stack.push(target);
+                        on_stack[target] = true;
+                        frames.push((target, 0));
+                    }
+                    Some(order) if on_stack[target] => {
+                        lowlink[node] = lowlink[node].min(order);
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            frames.pop();
+            if let Some(&(parent, _)) = frames.last() {
+                lowlink[parent] = lowlink[parent].min(lowlink[node]);
+            }
+
+            if Some(lowlink[node]) == discovered[node] {
+                let mut component = Vec::new();
+                while let Some(member) = stack.pop() {
+                    on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                component.reverse();
+                components.push(component);
+            }
+        }
+    }
+
+    // Tarjan's algorithm finishes a component only after every component
+    // reachable from it
+    components.reverse();
+    components
+}
+
+/// Index of the component holding each of the `n` nodes
+fn membership(components: &[Vec<usize>], n: usize) -> Vec<usize> {
+    let mut membership = vec![0; n];
+    for (index, component) in components.iter().enumerate() {
+        for &node in component {
+            membership[node] = index;
+        }
+    }
+
+    membership
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0 → 1 → 3
+    /// ↓   ↓
+    /// 2 → 4    5
+    fn successors(node: &u32) -> Vec<u32> {
+        match node {
+            0 => vec![1, 2],
+            1 => vec![3, 4],
+            2 => vec![4],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn breadth_first_distances() {
+        assert_eq!(
+            bfs(0, successors),
+            vec![(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)]
+        );
+        assert_eq!(bfs(5, successors), vec![(5, 0)]);
+    }
+
+    #[test]
+    fn depth_first_orders() {
+        let DepthFirst {
+            preorder,
+            postorder,
+        } = dfs(0, successors);
+
+        assert_eq!(preorder, vec![0, 1, 3, 4, 2]);
+        assert_eq!(postorder, vec![3, 4, 1, 2, 0]);
+    }
+
+    #[test]
+    fn depth_first_on_cycles() {
+        let ring = |n: &u32| [(n + 1) % 4, (n + 3) % 4];
+        let DepthFirst {
+            preorder,
+            postorder,
+        } = dfs(0, ring);
+
+        assert_eq!(preorder, vec![0, 1, 2, 3]);
+        assert_eq!(postorder, vec![3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn reachability() {
+        assert_eq!(reachable(1, successors), HashSet::from([1, 3, 4]));
+        assert_eq!(reachable(4, successors), HashSet::from([4]));
+    }
+
+    #[test]
+    fn topological_order() {
+        let order = topological_sort([5, 0], successors).unwrap();
+        assert_eq!(order, vec![5, 0, 1, 2, 3, 4]);
+
+        let order = topological_sort([4, 3, 2, 1, 0], successors).unwrap();
+        assert_eq!(order, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn topological_cycles() {
+        // 0 → 1 ⇄ 2 → 3, and 4 on its own
+        let successors = |node: &u32| match node {
+            0 => vec![1],
+            1 => vec![2],
+            2 => vec![1, 3],
+            _ => vec![],
+        };
+
+        let error = topological_sort(0..5, successors).unwrap_err();
+        assert_eq!(error, CycleError(vec![1, 2, 3]));
+        assert_eq!(
+            error.to_string(),
+            "graph contains a cycle; 3 nodes could not be ordered"
+        );
+    }
+
+    /// 0 → 1 ⇄ 2 → 3 → 4 → 5 → 3, with 6 looping on itself
+    fn knotted(node: &u32) -> Vec<u32> {
+        match node {
+            0 => vec![1],
+            1 => vec![2],
+            2 => vec![1, 3],
+            3 => vec![4],
+            4 => vec![5],
+            5 => vec![3],
+            6 => vec![6],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn strong_components() {
+        assert_eq!(
+            strongly_connected_components([0, 6], knotted),
+            vec![vec![6], vec![0], vec![1, 2], vec![3, 4, 5]]
+        );
+        assert_eq!(
+            strongly_connected_components([4, 3, 2, 1, 0], successors),
+            vec![vec![0], vec![1], vec![2], vec![3], vec![4]]
+        );
+    }
+
+    #[test]
+    fn condensed_components() {
+        let condensation = condensation([0], knotted);
+
+        assert_eq!(
+            condensation.components,
+            vec![vec![0], vec![1, 2], vec![3, 4, 5]]
+        );
+        assert_eq!(condensation.successors, vec![vec![1], vec![2], vec![]]);
+        assert_eq!(condensation.component_of[&5], 2);
+        assert_eq!(condensation.component_of.len(), 6);
+    }
+
+    #[test]
+    fn cycles() {
+        assert_eq!(find_cycle([0], knotted), Some(vec![1, 2]));
+        assert_eq!(find_cycle([3], knotted), Some(vec![3, 4, 5]));
+        assert_eq!(find_cycle([6], knotted), Some(vec![6]));
+        assert_eq!(find_cycle(0..6, successors), None);
+
+        // Long cycles are followed without recursion
+        let ring = |n: &u32| [(n + 1) % 100_000];
+        assert_eq!(
+            find_cycle([0], ring).map(|cycle| cycle.len()),
+            Some(100_000)
+        );
+    }
+}
@@ -0,0 +1,164 @@
+/// Disjoint sets of elements numbered from zero, merged with union by rank
+/// and searched with path compression so that every operation takes nearly
+/// constant amortized time.
+///
+/// # Examples
+///
+/// ```
+/// use advent::utils::graph::union_find::UnionFind;
+///
+/// let mut sets = UnionFind::new(5);
+/// assert!(sets.union(0, 1));
+/// assert!(sets.union(3, 1));
+/// assert!(!sets.union(0, 3));
+///
+/// assert!(sets.connected(3, 0));
+/// assert_eq!(sets.set_size(1), 3);
+/// assert_eq!(sets.count(), 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    size: Vec<usize>,
+    count: usize,
+}
+
+impl UnionFind {
+    /// Places each of `n` elements in a set of its own
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+            size: vec![1; n],
+            count: n,
+        }
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Number of disjoint sets
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Adds an element in a set of its own, returning its index
+    pub fn push(&mut self) -> usize {
+        let element = self.parent.len();
+        self.parent.push(element);
+        self.rank.push(0);
+        self.size.push(1);
+        self.count += 1;
+        element
+    }
+
+    /// Returns the representative of the set containing `element`, pointing
+    /// every element along the way directly at it
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        let mut current = element;
+        while self.parent[current] != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+        }
+
+        root
+    }
+
+    /// Merges the sets containing `a` and `b`, returning `false` if they were
+    /// already the same set
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (parent, child) = if self.rank[a] < self.rank[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        if self.rank[parent] == self.rank[child] {
+            self.rank[parent] += 1;
+        }
+
+        self.parent[child] = parent;
+        self.size[parent] += self.size[child];
+        self.count -= 1;
+        true
+    }
+
+    /// Whether `a` and `b` belong to the same set
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of elements in the set containing `element`
+    pub fn set_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.size[root]
+    }
+
+    /// Lists the elements of every set, ordering sets by their smallest
+    /// element and elements within each set in increasing order
+    pub fn sets(&mut self) -> Vec<Vec<usize>> {
+        let mut slots = vec![None; self.len()];
+        let mut sets: Vec<Vec<usize>> = Vec::with_capacity(self.count);
+        for element in 0..self.len() {
+            let root = self.find(element);
+            let slot = *slots[root].get_or_insert_with(|| {
+                sets.push(Vec::new());
+                sets.len() - 1
+            });
+            sets[slot].push(element);
+        }
+
+        sets
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merging_sets() {
+        let mut sets = UnionFind::new(6);
+        sets.union(4, 2);
+        sets.union(5, 0);
+        sets.union(2, 0);
+
+        assert_eq!(sets.count(), 3);
+        assert_eq!(sets.sets(), vec![vec![0, 2, 4, 5], vec![1], vec![3]]);
+        assert_eq!(sets.set_size(5), 4);
+        assert!(!sets.connected(1, 3));
+
+        let extra = sets.push();
+        assert_eq!((extra, sets.len(), sets.count()), (6, 7, 4));
+        assert!(sets.union(extra, 1));
+        assert_eq!(sets.set_size(1), 2);
+    }
+
+    #[test]
+    fn long_chains() {
+        let n = 100_000;
+        let mut sets = UnionFind::new(n);
+        for element in 1..n {
+            sets.union(element - 1, element);
+        }
+
+        assert_eq!(sets.count(), 1);
+        assert_eq!(sets.set_size(0), n);
+        assert!(sets.connected(0, n - 1));
+    }
+}
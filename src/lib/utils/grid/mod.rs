@@ -0,0 +1,868 @@
+mod sparse;
+mod view;
+
+pub use sparse::{Position, SparseGrid};
+pub use view::GridView;
+
+use std::{
+    collections::VecDeque,
+    fmt,
+    ops::{Index, IndexMut, Range},
+    str::FromStr,
+};
+
+pub mod error {
+    #[derive(Clone, Debug, thiserror::Error, PartialEq, Eq)]
+    pub enum Error {
+        #[error("string contains no data")]
+        EmptyInput,
+        #[error("expected {item} < {max_value} but got {value}")]
+        IndexOutOfBounds {
+            item: &'static str,
+            value: usize,
+            max_value: usize,
+        },
+        #[error("cannot convert {0} to {1}")]
+        DisallowedConversion(&'static str, &'static str),
+        #[error("unexpected character {0:?}")]
+        UnexpectedChar(char),
+        #[error("expected row {row} to have {expected} columns but got {actual}")]
+        RaggedRow {
+            row: usize,
+            expected: usize,
+            actual: usize,
+        },
+        #[error("expected {expected} values but got {actual}")]
+        WrongNumberOfValues { expected: usize, actual: usize },
+    }
+}
+
+/// Result type for [`Grid`] operations that can fail
+pub type Result<T> = std::result::Result<T, error::Error>;
+
+/// Type-safe representation of a location within a [`Grid`].
+///
+/// # Examples
+///
+/// The first `value` is at the top-left corner:
+/// ```
+/// use advent::utils::grid::*;
+///
+/// let dimensions = Dimensions::new(10, 10); // A 10-by-10 grid
+/// assert_eq!(Location::Index(0).as_coordinate(&dimensions), Ok(Location::Coordinate(0, 0)));
+/// assert_eq!(Location::Coordinate(0, 0).as_index(&dimensions), Ok(Location::Index(0)));
+/// ```
+/// A row-major 5-by-3 grid has the following indices:
+/// ```
+/// use advent::utils::grid::*;
+///
+/// // 0   1  2
+/// // 3   4  5
+/// // 6   7  8
+/// // 9  10 11
+/// // 12 13 14
+/// let dimensions = Dimensions::new(5, 3);
+/// assert_eq!(Location::Coordinate(1, 2).as_index(&dimensions), Ok(Location::Index(5)));
+/// println!("{:?}", Location::Coordinate(2, 3).as_index(&dimensions));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Location {
+    /// Index variant. Reprsents offsets from the first value stored for a
+    /// [`Grid`]. Space-efficient way to store a collection of [`Location`]s.
+    Index(usize),
+    /// Coordinate variant. Straightforward for computing neighbors.
+    Coordinate(usize, usize),
+}
+
+impl Location {
+    /// Converts a [`Location`] to its index representation, ensuring the
+    /// resulting [`Location::Index`] lies within the specified dimensions.
+    pub fn as_index(&self, dimensions: &Dimensions) -> Result<Self> {
+        match self {
+            Self::Index(idx) => {
+                dimensions.check_index(*idx)?;
+                Ok(*self)
+            }
+            Self::Coordinate(r, c) => {
+                dimensions.check_row_and_col(*r, *c)?;
+                Ok(Self::Index(r * dimensions.cols + c))
+            }
+        }
+    }
+
+    /// Converts a [`Location`] to its coordinate representation, ensuring the
+    /// resulting [`Location::Index`] lies within the specified dimensions.
+    pub fn as_coordinate(&self, dimensions: &Dimensions) -> Result<Self> {
+        match self {
+            Self::Coordinate(r, c) => {
+                dimensions.check_row_and_col(*r, *c)?;
+                Ok(*self)
+            }
+            Self::Index(idx) => {
+                dimensions.check_index(*idx)?;
+                Ok(Self::Coordinate(
+                    idx / dimensions.cols,
+                    idx % dimensions.cols,
+                ))
+            }
+        }
+    }
+
+    /// Converts a [`Location`] to its index representation, ignoring bounds
+    /// imposed by `dimensions`. A [`Location::Index`] produced this way can
+    /// exceed the maximum index allowed by `dimensions`.
+    pub fn as_index_unchecked(&self, dimensions: &Dimensions) -> Self {
+        match self {
+            Self::Index(_) => *self,
+            Self::Coordinate(r, c) => Self::Index(r * dimensions.cols + c),
+        }
+    }
+
+    /// Converts a [`Location`] to its coordinate representation, ignoring
+    /// bounds imposed by `dimensions`. a [`Location::Coordinate`] produced this
+    /// way can have more rows, more columns, or more rows *and* columns than
+    /// what's allowed by `dimensions`.
+    pub fn as_coordinate_unchecked(&self, dimensions: &Dimensions) -> Self {
+        match self {
+            Self::Coordinate(_, _) => *self,
+            Self::Index(idx) => Self::Coordinate(idx / dimensions.cols, idx % dimensions.cols),
+        }
+    }
+
+    /// Returns the offset of a [`Location`] from the first value of a
+    /// [`Grid`] with the specified dimensions.
+    pub fn index(&self, dimensions: &Dimensions) -> Result<usize> {
+        match self.as_index(dimensions)? {
+            Self::Index(idx) => Ok(idx),
+            Self::Coordinate(_, _) => unreachable!("as_index always yields an index"),
+        }
+    }
+
+    /// Returns the `(row, column)` pair of a [`Location`] within a [`Grid`]
+    /// with the specified dimensions.
+    pub fn coordinate(&self, dimensions: &Dimensions) -> Result<(usize, usize)> {
+        match self.as_coordinate(dimensions)? {
+            Self::Coordinate(r, c) => Ok((r, c)),
+            Self::Index(_) => unreachable!("as_coordinate always yields a coordinate"),
+        }
+    }
+}
+
+impl From<(usize, usize)> for Location {
+    fn from((row, col): (usize, usize)) -> Self {
+        Self::Coordinate(row, col)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Dimensions {
+    rows: usize,
+    cols: usize,
+}
+
+impl Dimensions {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self { rows, cols }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn max_index(&self) -> usize {
+        self.rows * self.cols
+    }
+
+    fn check(value: usize, max_value: usize, name: &'static str) -> Result<()> {
+        (value < max_value)
+            .then_some(())
+            .ok_or(error::Error::IndexOutOfBounds {
+                item: name,
+                value,
+                max_value,
+            })
+    }
+
+    pub fn check_index(&self, index: usize) -> Result<()> {
+        Self::check(index, self.max_index(), "index")
+    }
+
+    pub fn check_row(&self, row: usize) -> Result<()> {
+        Self::check(row, self.rows, "row")
+    }
+
+    pub fn check_col(&self, col: usize) -> Result<()> {
+        Self::check(col, self.cols, "column")
+    }
+
+    pub fn check_row_and_col(&self, row: usize, col: usize) -> Result<()> {
+        self.check_row(row)?;
+        self.check_col(col)
+    }
+}
+
+/// Row and column displacement between two [`Location`]s. Negative values
+/// point up and to the left.
+pub type Offset = (isize, isize);
+
+/// Set of offsets considered adjacent to a [`Location`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Connectivity {
+    /// The four locations sharing an edge (north, west, east, south).
+    Orthogonal,
+    /// The four locations sharing only a corner.
+    Diagonal,
+    /// All eight surrounding locations, i.e. the Moore neighborhood.
+    Moore,
+}
+
+impl Connectivity {
+    /// Offsets belonging to this neighborhood, in row-major order.
+    pub fn offsets(&self) -> &'static [Offset] {
+        match self {
+            Self::Orthogonal => &[(-1, 0), (0, -1), (0, 1), (1, 0)],
+            Self::Diagonal => &[(-1, -1), (-1, 1), (1, -1), (1, 1)],
+            Self::Moore => &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+        }
+    }
+}
+
+/// Treatment of offsets that leave the edge of a [`Grid`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Boundary {
+    /// Locations outside the grid are discarded.
+    Clip,
+    /// Locations outside the grid reappear on the opposite edge, as on a
+    /// torus. On grids narrower than the offset this can yield the original
+    /// location or repeat a neighbor.
+    Wrap,
+}
+
+/// Dense, row-major collection of values addressed by [`Location`].
+///
+/// # Examples
+///
+/// ```
+/// use advent::utils::grid::*;
+///
+/// let grid = Grid::from_str_with("123\n456", |ch| {
+///     ch.to_digit(10).ok_or(error::Error::UnexpectedChar(ch))
+/// })
+/// .unwrap();
+///
+/// assert_eq!(grid[Location::Coordinate(1, 0)], 4);
+/// assert_eq!(grid.get(Location::Index(2)), Some(&3));
+/// assert_eq!(grid.get(Location::Coordinate(2, 0)), None);
+/// assert_eq!(grid.to_string(), "123\n456");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    values: Vec<T>,
+    dimensions: Dimensions,
+}
+
+impl<T> Grid<T> {
+    /// Constructs a [`Grid`] from row-major `values`, which must contain
+    /// exactly `dimensions.max_index()` items.
+    pub fn new(dimensions: Dimensions, values: Vec<T>) -> Result<Self> {
+        if values.len() != dimensions.max_index() {
+            return Err(error::Error::WrongNumberOfValues {
+                expected: dimensions.max_index(),
+                actual: values.len(),
+            });
+        }
+
+        Ok(Self { values, dimensions })
+    }
+
+    /// Constructs a [`Grid`] with every location set to `value`.
+    pub fn filled(dimensions: Dimensions, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            values: vec![value; dimensions.max_index()],
+            dimensions,
+        }
+    }
+
+    /// Parses a [`Grid`] from newline-separated rows, converting each
+    /// character with `f`. Every row must contain the same number of
+    /// characters.
+    pub fn from_str_with<F>(s: &str, mut f: F) -> Result<Self>
+    where
+        F: FnMut(char) -> Result<T>,
+    {
+        let num_cols = s
+            .lines()
+            .next()
+            .ok_or(error::Error::EmptyInput)?
+            .chars()
+            .count();
+
+        let mut values = Vec::new();
+        let mut num_rows = 0;
+        for (row, line) in s.lines().enumerate() {
+            let len_before = values.len();
+            for ch in line.chars() {
+                values.push(f(ch)?);
+            }
+
+            let actual = values.len() - len_before;
+            if actual != num_cols {
+                return Err(error::Error::RaggedRow {
+                    row,
+                    expected: num_cols,
+                    actual,
+                });
+            }
+            num_rows += 1;
+        }
+
+        Ok(Self {
+            values,
+            dimensions: Dimensions::new(num_rows, num_cols),
+        })
+    }
+
+    pub fn dimensions(&self) -> &Dimensions {
+        &self.dimensions
+    }
+
+    pub fn rows(&self) -> usize {
+        self.dimensions.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.dimensions.cols
+    }
+
+    /// Returns a reference to the value at `location`, or `None` if it lies
+    /// outside the grid.
+    pub fn get(&self, location: Location) -> Option<&T> {
+        let idx = location.index(&self.dimensions).ok()?;
+        self.values.get(idx)
+    }
+
+    /// Returns a mutable reference to the value at `location`, or `None` if
+    /// it lies outside the grid.
+    pub fn get_mut(&mut self, location: Location) -> Option<&mut T> {
+        let idx = location.index(&self.dimensions).ok()?;
+        self.values.get_mut(idx)
+    }
+
+    /// Iterates over all values in row-major order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.values.iter()
+    }
+
+    /// Iterates mutably over all values in row-major order.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.values.iter_mut()
+    }
+
+    /// Iterates over every [`Location::Coordinate`] in row-major order.
+    pub fn locations(&self) -> impl Iterator<Item = Location> {
+        let cols = self.dimensions.cols;
+        (0..self.dimensions.max_index())
+            .map(move |idx| Location::Coordinate(idx / cols, idx % cols))
+    }
+
+    /// Iterates over every value paired with its [`Location::Coordinate`].
+    pub fn enumerate(&self) -> impl Iterator<Item = (Location, &T)> {
+        self.locations().zip(self.values.iter())
+    }
+
+    /// Returns the values in `row`, or `None` if it lies outside the grid.
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        self.dimensions.check_row(row).ok()?;
+        let start = row * self.dimensions.cols;
+        Some(&self.values[start..start + self.dimensions.cols])
+    }
+
+    /// Iterates over the values in `col` from top to bottom, or returns `None`
+    /// if it lies outside the grid.
+    pub fn column(&self, col: usize) -> Option<impl Iterator<Item = &T>> {
+        self.dimensions.check_col(col).ok()?;
+        Some(self.values.iter().skip(col).step_by(self.dimensions.cols))
+    }
+
+    /// Iterates over each row as a slice.
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.dimensions.rows).flat_map(|row| self.row(row))
+    }
+
+    /// Iterates over each column, yielding an iterator over its values.
+    pub fn iter_columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.dimensions.cols).flat_map(|col| self.column(col))
+    }
+
+    /// Borrows the whole grid as an untransformed [`GridView`].
+    pub fn view(&self) -> GridView<'_, T> {
+        GridView::new(self)
+    }
+
+    /// Views the grid with rows and columns swapped. See
+    /// [`GridView::transpose`].
+    pub fn transpose(&self) -> GridView<'_, T> {
+        self.view().transpose()
+    }
+
+    /// Views the grid rotated a quarter turn clockwise. See
+    /// [`GridView::rotate_cw`].
+    pub fn rotate_cw(&self) -> GridView<'_, T> {
+        self.view().rotate_cw()
+    }
+
+    /// Views the grid rotated a quarter turn counterclockwise. See
+    /// [`GridView::rotate_ccw`].
+    pub fn rotate_ccw(&self) -> GridView<'_, T> {
+        self.view().rotate_ccw()
+    }
+
+    /// Views the grid mirrored left to right. See [`GridView::flip_h`].
+    pub fn flip_h(&self) -> GridView<'_, T> {
+        self.view().flip_h()
+    }
+
+    /// Views the grid mirrored top to bottom. See [`GridView::flip_v`].
+    pub fn flip_v(&self) -> GridView<'_, T> {
+        self.view().flip_v()
+    }
+
+    /// Views the rectangle covering the half-open ranges `rows` and `cols`.
+    /// See [`GridView::subgrid`].
+    pub fn subgrid(&self, rows: Range<usize>, cols: Range<usize>) -> Result<GridView<'_, T>> {
+        self.view().subgrid(rows, cols)
+    }
+
+    /// Returns the [`Location::Coordinate`] displaced from `location` by
+    /// `offset`, or `None` if `location` lies outside the grid or the result
+    /// is clipped by `boundary`.
+    pub fn step(&self, location: Location, offset: Offset, boundary: Boundary) -> Option<Location> {
+        let (r, c) = location.coordinate(&self.dimensions).ok()?;
+        let (rows, cols) = (self.dimensions.rows as isize, self.dimensions.cols as isize);
+        let (r, c) = (r as isize + offset.0, c as isize + offset.1);
+
+        match boundary {
+            Boundary::Clip => ((0..rows).contains(&r) && (0..cols).contains(&c))
+                .then_some(Location::Coordinate(r as usize, c as usize)),
+            Boundary::Wrap => Some(Location::Coordinate(
+                r.rem_euclid(rows) as usize,
+                c.rem_euclid(cols) as usize,
+            )),
+        }
+    }
+
+    /// Iterates over the neighbors of `location` described by `connectivity`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent::utils::grid::*;
+    /// use std::str::FromStr;
+    ///
+    /// let grid = Grid::from_str("abc\ndef\nghi").unwrap();
+    /// let corner = Location::Coordinate(0, 0);
+    ///
+    /// let clipped = grid.neighbors(corner, Connectivity::Moore, Boundary::Clip);
+    /// assert_eq!(clipped.map(|l| grid[l]).collect::<String>(), "bde");
+    ///
+    /// let wrapped = grid.neighbors(corner, Connectivity::Orthogonal, Boundary::Wrap);
+    /// assert_eq!(wrapped.map(|l| grid[l]).collect::<String>(), "gcbd");
+    /// ```
+    pub fn neighbors(
+        &self,
+        location: Location,
+        connectivity: Connectivity,
+        boundary: Boundary,
+    ) -> impl Iterator<Item = Location> + Clone + '_ {
+        connectivity
+            .offsets()
+            .iter()
+            .filter_map(move |&offset| self.step(location, offset, boundary))
+    }
+
+    /// Iterates over successive locations from `start` (exclusive) in steps
+    /// of `direction` until leaving the grid. Useful for line-of-sight
+    /// queries. A `direction` of `(0, 0)` never terminates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent::utils::grid::*;
+    /// use std::str::FromStr;
+    ///
+    /// let grid = Grid::from_str("abcd\nefgh\nijkl").unwrap();
+    /// let ray = grid.ray(Location::Coordinate(0, 0), (1, 1));
+    /// assert_eq!(ray.map(|l| grid[l]).collect::<String>(), "fk");
+    /// ```
+    pub fn ray(&self, start: Location, direction: Offset) -> impl Iterator<Item = Location> + '_ {
+        std::iter::successors(
+            self.step(start, direction, Boundary::Clip),
+            move |&location| self.step(location, direction, Boundary::Clip),
+        )
+    }
+
+    /// Collects every location orthogonally reachable from `start` through
+    /// values satisfying `predicate`, in breadth-first order. The result is
+    /// empty if `start` lies outside the grid or fails `predicate`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent::utils::grid::*;
+    /// use std::str::FromStr;
+    ///
+    /// let grid = Grid::from_str("..#\n.##\n#..").unwrap();
+    /// let region = grid.flood_fill(Location::Coordinate(0, 0), |&ch| ch == '.');
+    /// assert_eq!(region.len(), 3);
+    /// ```
+    pub fn flood_fill<P: Fn(&T) -> bool>(&self, start: Location, predicate: P) -> Vec<Location> {
+        let mut visited = Grid::filled(self.dimensions, false);
+        self.fill_from(start, &predicate, &mut visited)
+    }
+
+    /// Partitions the values satisfying `predicate` into orthogonally
+    /// connected components. Components are ordered by their first location
+    /// in row-major order.
+    pub fn components<P: Fn(&T) -> bool>(&self, predicate: P) -> Vec<Vec<Location>> {
+        let mut visited = Grid::filled(self.dimensions, false);
+
+        self.locations()
+            .filter_map(|location| {
+                let component = self.fill_from(location, &predicate, &mut visited);
+                (!component.is_empty()).then_some(component)
+            })
+            .collect()
+    }
+
+    /// Number of locations in each of [`Grid::components`].
+    pub fn component_sizes<P: Fn(&T) -> bool>(&self, predicate: P) -> Vec<usize> {
+        self.components(predicate).iter().map(Vec::len).collect()
+    }
+
+    /// Labels every location with the index of its entry in
+    /// [`Grid::components`], or `None` if its value fails `predicate`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent::utils::grid::*;
+    /// use std::str::FromStr;
+    ///
+    /// let grid = Grid::from_str("#.#\n#..\n.##").unwrap();
+    /// let labels = grid.component_labels(|&ch| ch == '#');
+    /// let display = labels.map(|label| label.map_or('.', |n| (b'a' + n as u8) as char));
+    /// assert_eq!(display.to_string(), "a.b\na..\n.cc");
+    /// ```
+    pub fn component_labels<P: Fn(&T) -> bool>(&self, predicate: P) -> Grid<Option<usize>> {
+        let mut labels = Grid::filled(self.dimensions, None);
+        for (label, component) in self.components(predicate).into_iter().enumerate() {
+            for location in component {
+                labels[location] = Some(label);
+            }
+        }
+
+        labels
+    }
+
+    fn fill_from<P: Fn(&T) -> bool>(
+        &self,
+        start: Location,
+        predicate: &P,
+        visited: &mut Grid<bool>,
+    ) -> Vec<Location> {
+        let accept = |location: Location, visited: &mut Grid<bool>| {
+            let unvisited = visited.get(location) == Some(&false);
+            let accepted = unvisited && predicate(&self[location]);
+            if accepted {
+                visited[location] = true;
+            }
+            accepted
+        };
+
+        let Ok(start) = start.as_coordinate(&self.dimensions) else {
+            return Vec::new();
+        };
+
+        let mut region = Vec::new();
+        let mut queue = VecDeque::new();
+        if accept(start, visited) {
+            queue.push_back(start);
+        }
+
+        while let Some(location) = queue.pop_front() {
+            region.push(location);
+            for neighbor in self.neighbors(location, Connectivity::Orthogonal, Boundary::Clip) {
+                if accept(neighbor, visited) {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        region
+    }
+
+    /// Constructs a new [`Grid`] of identical dimensions by applying `f` to
+    /// every value.
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            values: self.values.iter().map(f).collect(),
+            dimensions: self.dimensions,
+        }
+    }
+}
+
+impl<T> Index<Location> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, location: Location) -> &Self::Output {
+        match location.index(&self.dimensions) {
+            Ok(idx) => &self.values[idx],
+            Err(err) => panic!("{err}"),
+        }
+    }
+}
+
+impl<T> IndexMut<Location> for Grid<T> {
+    fn index_mut(&mut self, location: Location) -> &mut Self::Output {
+        match location.index(&self.dimensions) {
+            Ok(idx) => &mut self.values[idx],
+            Err(err) => panic!("{err}"),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.iter_rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            for value in row {
+                write!(f, "{value}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = error::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_str_with(s, Ok)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn char_grid_from_string() {
+        const INPUT: &str = "abc\ndef\nghi";
+        let char_grid = Grid::from_str(INPUT).unwrap();
+
+        assert_eq!(char_grid.values, ('a'..='i').collect::<Vec<_>>());
+        assert_eq!(char_grid.dimensions, Dimensions::new(3, 3));
+    }
+
+    #[test]
+    fn ragged_rows() {
+        assert_eq!(
+            Grid::from_str("abc\nde"),
+            Err(error::Error::RaggedRow {
+                row: 1,
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert_eq!(Grid::from_str(""), Err(error::Error::EmptyInput));
+    }
+
+    #[test]
+    fn from_str_with() {
+        let grid = Grid::from_str_with("#.\n.#\n##", |ch| match ch {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(error::Error::UnexpectedChar(ch)),
+        })
+        .unwrap();
+
+        assert_eq!(grid.dimensions(), &Dimensions::new(3, 2));
+        assert_eq!(grid.iter().filter(|&&lit| lit).count(), 4);
+        assert_eq!(
+            Grid::from_str_with("#x", |ch| match ch {
+                '#' => Ok(true),
+                _ => Err(error::Error::UnexpectedChar(ch)),
+            }),
+            Err(error::Error::UnexpectedChar('x'))
+        );
+    }
+
+    #[test]
+    fn indexing() {
+        let mut grid = Grid::from_str("abc\ndef").unwrap();
+
+        assert_eq!(grid[Location::Coordinate(1, 2)], 'f');
+        assert_eq!(grid[Location::Index(4)], 'e');
+        assert_eq!(grid.get(Location::Coordinate(0, 3)), None);
+        assert_eq!(grid.get(Location::Index(6)), None);
+
+        grid[Location::Coordinate(0, 0)] = 'z';
+        *grid.get_mut(Location::Index(1)).unwrap() = 'y';
+        assert_eq!(grid.row(0), Some(&['z', 'y', 'c'][..]));
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::from_str("abc\ndef").unwrap();
+
+        assert_eq!(
+            grid.iter_rows()
+                .map(|row| row.iter().collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["abc", "def"]
+        );
+        assert_eq!(
+            grid.iter_columns()
+                .map(|col| col.collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+        assert!(grid.row(2).is_none());
+        assert!(grid.column(3).is_none());
+    }
+
+    #[test]
+    fn display() {
+        const INPUT: &str = "ab\ncd\nef";
+        let grid = Grid::from_str(INPUT).unwrap();
+        assert_eq!(grid.to_string(), INPUT);
+        assert_eq!(
+            grid.map(|ch| ch.to_ascii_uppercase()).to_string(),
+            "AB\nCD\nEF"
+        );
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = Grid::from_str("abcd\nefgh\nijkl").unwrap();
+        let collect = |location, connectivity, boundary| {
+            grid.neighbors(location, connectivity, boundary)
+                .map(|l| grid[l])
+                .collect::<String>()
+        };
+
+        let center = Location::Coordinate(1, 1);
+        assert_eq!(
+            collect(center, Connectivity::Orthogonal, Boundary::Clip),
+            "begj"
+        );
+        assert_eq!(
+            collect(center, Connectivity::Diagonal, Boundary::Clip),
+            "acik"
+        );
+        assert_eq!(
+            collect(center, Connectivity::Moore, Boundary::Clip),
+            "abcegijk"
+        );
+
+        let corner = Location::Index(11);
+        assert_eq!(collect(corner, Connectivity::Moore, Boundary::Clip), "ghk");
+        assert_eq!(
+            collect(corner, Connectivity::Moore, Boundary::Wrap),
+            "ghekicda"
+        );
+        assert_eq!(
+            collect(corner, Connectivity::Diagonal, Boundary::Wrap),
+            "geca"
+        );
+
+        assert_eq!(
+            grid.neighbors(Location::Index(12), Connectivity::Moore, Boundary::Wrap)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn rays() {
+        let grid = Grid::from_str("abcd\nefgh\nijkl").unwrap();
+        let cast = |start, direction| {
+            grid.ray(start, direction)
+                .map(|l| grid[l])
+                .collect::<String>()
+        };
+
+        assert_eq!(cast(Location::Coordinate(1, 0), (0, 1)), "fgh");
+        assert_eq!(cast(Location::Coordinate(2, 3), (-1, -1)), "gb");
+        assert_eq!(cast(Location::Coordinate(0, 3), (-1, 0)), "");
+        assert_eq!(cast(Location::Coordinate(0, 0), (1, 2)), "g");
+    }
+
+    #[test]
+    fn flood_fill() {
+        let grid = Grid::from_str("..#..\n.##..\n#...#").unwrap();
+
+        let region = grid.flood_fill(Location::Index(3), |&ch| ch == '.');
+        assert_eq!(region.len(), 7);
+        assert_eq!(region[0], Location::Coordinate(0, 3));
+        assert!(!region.contains(&Location::Coordinate(0, 0)));
+
+        assert!(grid
+            .flood_fill(Location::Index(2), |&ch| ch == '.')
+            .is_empty());
+        assert!(grid
+            .flood_fill(Location::Index(15), |&ch| ch == '.')
+            .is_empty());
+    }
+
+    #[test]
+    fn components() {
+        let grid = Grid::from_str("..#..\n.##..\n#...#").unwrap();
+
+        assert_eq!(grid.component_sizes(|&ch| ch == '.'), vec![3, 7]);
+        assert_eq!(grid.component_sizes(|&ch| ch == '#'), vec![3, 1, 1]);
+        assert_eq!(
+            grid.components(|&ch| ch == '#')[1],
+            vec![Location::Coordinate(2, 0)]
+        );
+        assert!(grid.components(|&ch| ch == 'x').is_empty());
+
+        let labels = grid.component_labels(|&ch| ch == '#');
+        assert_eq!(labels[Location::Coordinate(1, 1)], Some(0));
+        assert_eq!(labels[Location::Coordinate(2, 4)], Some(2));
+        assert_eq!(labels[Location::Coordinate(0, 0)], None);
+    }
+
+    #[test]
+    fn unchecked_conversions_on_non_square_grids() {
+        let dimensions = Dimensions::new(2, 5);
+        assert_eq!(
+            Location::Index(7).as_coordinate_unchecked(&dimensions),
+            Location::Coordinate(1, 2)
+        );
+        assert_eq!(
+            Location::Coordinate(1, 2).as_index_unchecked(&dimensions),
+            Location::Index(7)
+        );
+    }
+}
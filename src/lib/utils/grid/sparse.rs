@@ -0,0 +1,365 @@
+use super::{Connectivity, Dimensions, Grid};
+use glam::{IVec2, IVec3, IVec4};
+use std::collections::{hash_map, HashMap};
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+/// Signed integer vector usable as a [`SparseGrid`] position.
+pub trait Position: Copy + Eq + Hash {
+    /// Smallest of each component of `self` and `other`
+    fn min(self, other: Self) -> Self;
+
+    /// Largest of each component of `self` and `other`
+    fn max(self, other: Self) -> Self;
+
+    /// Whether any component of `self` equals that of `other`
+    fn shares_component(self, other: Self) -> bool;
+}
+
+macro_rules! impl_position {
+    ($($vec:ty),*) => {
+        $(
+            impl Position for $vec {
+                fn min(self, other: Self) -> Self {
+                    <$vec>::min(self, other)
+                }
+
+                fn max(self, other: Self) -> Self {
+                    <$vec>::max(self, other)
+                }
+
+                fn shares_component(self, other: Self) -> bool {
+                    self.cmpeq(other).any()
+                }
+            }
+        )*
+    };
+}
+
+impl_position!(IVec2, IVec3, IVec4);
+
+/// Unbounded grid that stores values only at occupied positions.
+///
+/// Positions are signed [`IVec2`]s where `x` is the column and `y` is the
+/// row, so rendering places increasing `y` further down the page. Any other
+/// [`Position`], such as an [`IVec3`], may be used for grids that are never
+/// rendered. The bounding box of all occupied positions is tracked as values
+/// are inserted and removed.
+///
+/// # Examples
+///
+/// ```
+/// use advent::utils::grid::SparseGrid;
+/// use glam::IVec2;
+///
+/// let mut grid = SparseGrid::new();
+/// grid.insert(IVec2::new(-1, 0), '#');
+/// grid.insert(IVec2::new(1, 1), '#');
+///
+/// assert_eq!(grid.bounds(), Some((IVec2::new(-1, 0), IVec2::new(1, 1))));
+/// assert_eq!(
+///     grid.render(|value| *value.unwrap_or(&'.')).to_string(),
+///     "#..\n..#"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T, P: Position = IVec2> {
+    values: HashMap<P, T>,
+    bounds: Option<(P, P)>,
+}
+
+impl<T, P: Position> SparseGrid<T, P> {
+    pub fn new() -> Self {
+        Self {
+            values: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Smallest and largest corners (inclusive) of the box enclosing every
+    /// occupied position, or `None` if the grid is empty.
+    pub fn bounds(&self) -> Option<(P, P)> {
+        self.bounds
+    }
+
+    pub fn contains(&self, position: P) -> bool {
+        self.values.contains_key(&position)
+    }
+
+    pub fn get(&self, position: P) -> Option<&T> {
+        self.values.get(&position)
+    }
+
+    pub fn get_mut(&mut self, position: P) -> Option<&mut T> {
+        self.values.get_mut(&position)
+    }
+
+    /// Stores `value` at `position`, returning the value previously there.
+    pub fn insert(&mut self, position: P, value: T) -> Option<T> {
+        self.grow(position);
+        self.values.insert(position, value)
+    }
+
+    /// Returns the value at `position`, first inserting the result of `f` if
+    /// the position is unoccupied.
+    pub fn get_or_insert_with<F: FnOnce() -> T>(&mut self, position: P, f: F) -> &mut T {
+        self.grow(position);
+        self.values.entry(position).or_insert_with(f)
+    }
+
+    /// Removes and returns the value at `position`. The bounding box shrinks
+    /// if `position` was on its edge.
+    pub fn remove(&mut self, position: P) -> Option<T> {
+        let value = self.values.remove(&position)?;
+
+        if let Some((min, max)) = self.bounds {
+            if position.shares_component(min) || position.shares_component(max) {
+                self.bounds = Self::enclose(self.values.keys().copied());
+            }
+        }
+
+        Some(value)
+    }
+
+    /// Iterates over occupied positions in arbitrary order.
+    pub fn positions(&self) -> impl Iterator<Item = P> + '_ {
+        self.values.keys().copied()
+    }
+
+    /// Iterates over occupied positions and their values in arbitrary order.
+    pub fn iter(&self) -> hash_map::Iter<'_, P, T> {
+        self.values.iter()
+    }
+
+    fn grow(&mut self, position: P) {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (min.min(position), max.max(position)),
+            None => (position, position),
+        });
+    }
+
+    fn enclose<I: Iterator<Item = P>>(positions: I) -> Option<(P, P)> {
+        positions.fold(None, |bounds, position| {
+            Some(match bounds {
+                Some((min, max)) => (position.min(min), position.max(max)),
+                None => (position, position),
+            })
+        })
+    }
+}
+
+impl<T> SparseGrid<T> {
+    /// Iterates over every position adjacent to `position` described by
+    /// `connectivity`, whether occupied or not.
+    pub fn neighbors(
+        &self,
+        position: IVec2,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = IVec2> + Clone {
+        connectivity
+            .offsets()
+            .iter()
+            .map(move |&(dr, dc)| position + IVec2::new(dc as i32, dr as i32))
+    }
+
+    /// Iterates over the occupied positions adjacent to `position` and their
+    /// values.
+    pub fn occupied_neighbors(
+        &self,
+        position: IVec2,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = (IVec2, &T)> + '_ {
+        self.neighbors(position, connectivity)
+            .filter_map(|neighbor| self.get(neighbor).map(|value| (neighbor, value)))
+    }
+
+    /// Renders the bounding box to a dense [`Grid`], converting each position
+    /// with `f`. The top-left corner of the result corresponds to the
+    /// smallest corner of [`SparseGrid::bounds`]. An empty grid renders to an
+    /// empty [`Grid`].
+    pub fn render<F: FnMut(Option<&T>) -> char>(&self, mut f: F) -> Grid<char> {
+        let Some((min, max)) = self.bounds else {
+            return Grid::default();
+        };
+
+        // Widen before subtracting, as a box spanning most of the `i32` range
+        // is wider than `i32::MAX`
+        let extent = |low: i32, high: i32| (i64::from(high) - i64::from(low) + 1) as usize;
+        let (rows, cols) = (extent(min.y, max.y), extent(min.x, max.x));
+        let values = (min.y..=max.y)
+            .flat_map(|y| (min.x..=max.x).map(move |x| IVec2::new(x, y)))
+            .map(|position| f(self.get(position)))
+            .collect();
+
+        Grid::new(Dimensions::new(rows, cols), values)
+            .expect("bounding box matches the number of rendered values")
+    }
+}
+
+impl<T, P: Position> Default for SparseGrid<T, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Hash, P: Position> Hash for SparseGrid<T, P> {
+    /// Combines the hashes of the occupied positions and their values
+    /// independently of their iteration order so that equal grids hash
+    /// equally.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let combined = self.values.iter().fold(0_u64, |acc, entry| {
+            let mut hasher = DefaultHasher::new();
+            entry.hash(&mut hasher);
+            acc.wrapping_add(hasher.finish())
+        });
+
+        self.values.len().hash(state);
+        combined.hash(state);
+    }
+}
+
+impl<T, P: Position> FromIterator<(P, T)> for SparseGrid<T, P> {
+    fn from_iter<I: IntoIterator<Item = (P, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T, P: Position> Extend<(P, T)> for SparseGrid<T, P> {
+    fn extend<I: IntoIterator<Item = (P, T)>>(&mut self, iter: I) {
+        for (position, value) in iter {
+            self.insert(position, value);
+        }
+    }
+}
+
+impl<P: Position> FromIterator<P> for SparseGrid<(), P> {
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        iter.into_iter().map(|position| (position, ())).collect()
+    }
+}
+
+impl<P: Position> Extend<P> for SparseGrid<(), P> {
+    fn extend<I: IntoIterator<Item = P>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().map(|position| (position, ())));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_follow_insertions_and_removals() {
+        let mut grid: SparseGrid<()> = [IVec2::new(0, 0), IVec2::new(3, -2), IVec2::new(-1, 5)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.bounds(), Some((IVec2::new(-1, -2), IVec2::new(3, 5))));
+
+        grid.remove(IVec2::new(0, 0));
+        assert_eq!(grid.bounds(), Some((IVec2::new(-1, -2), IVec2::new(3, 5))));
+
+        grid.remove(IVec2::new(3, -2));
+        assert_eq!(grid.bounds(), Some((IVec2::new(-1, 5), IVec2::new(-1, 5))));
+
+        grid.remove(IVec2::new(-1, 5));
+        assert_eq!(grid.bounds(), None);
+        assert!(grid.is_empty());
+    }
+
+    #[test]
+    fn higher_dimensions() {
+        let cubes = [
+            IVec3::new(0, 0, 0),
+            IVec3::new(2, -1, 4),
+            IVec3::new(1, 1, -3),
+        ];
+        let mut grid: SparseGrid<(), IVec3> = cubes.into_iter().collect();
+        assert_eq!(
+            grid.bounds(),
+            Some((IVec3::new(0, -1, -3), IVec3::new(2, 1, 4)))
+        );
+
+        let reversed: SparseGrid<(), IVec3> = cubes.into_iter().rev().collect();
+        let hash = |grid: &SparseGrid<(), IVec3>| {
+            let mut hasher = DefaultHasher::new();
+            grid.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(grid, reversed);
+        assert_eq!(hash(&grid), hash(&reversed));
+
+        grid.remove(IVec3::new(2, -1, 4));
+        assert_eq!(
+            grid.bounds(),
+            Some((IVec3::new(0, 0, -3), IVec3::new(1, 1, 0)))
+        );
+        assert_ne!(hash(&grid), hash(&reversed));
+    }
+
+    #[test]
+    fn counting() {
+        let mut grid = SparseGrid::new();
+        for position in [IVec2::ZERO, IVec2::X, IVec2::ZERO] {
+            *grid.get_or_insert_with(position, || 0) += 1;
+        }
+
+        assert_eq!(grid.get(IVec2::ZERO), Some(&2));
+        assert_eq!(grid.get(IVec2::X), Some(&1));
+        assert_eq!(grid.get(IVec2::Y), None);
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid: SparseGrid<()> = [IVec2::new(1, 0), IVec2::new(1, 1), IVec2::new(5, 5)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(grid.neighbors(IVec2::ZERO, Connectivity::Moore).count(), 8);
+        assert_eq!(
+            grid.occupied_neighbors(IVec2::ZERO, Connectivity::Orthogonal)
+                .map(|(position, _)| position)
+                .collect::<Vec<_>>(),
+            vec![IVec2::new(1, 0)]
+        );
+        assert_eq!(
+            grid.occupied_neighbors(IVec2::ZERO, Connectivity::Moore)
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn render_non_square() {
+        let grid: SparseGrid<()> = [IVec2::new(-2, 3), IVec2::new(1, 4)].into_iter().collect();
+        let rendered = grid.render(|value| if value.is_some() { '#' } else { '.' });
+
+        assert_eq!(rendered.dimensions(), &Dimensions::new(2, 4));
+        assert_eq!(rendered.to_string(), "#...\n...#");
+        assert_eq!(SparseGrid::<()>::new().render(|_| '#').to_string(), "");
+    }
+
+    #[test]
+    fn render_at_coordinate_limits() {
+        let grid: SparseGrid<()> = [
+            IVec2::new(i32::MAX, i32::MIN),
+            IVec2::new(i32::MAX - 2, i32::MIN + 1),
+        ]
+        .into_iter()
+        .collect();
+        let rendered = grid.render(|value| if value.is_some() { '#' } else { '.' });
+
+        assert_eq!(rendered.dimensions(), &Dimensions::new(2, 3));
+        assert_eq!(rendered.to_string(), "..#\n#..");
+    }
+}
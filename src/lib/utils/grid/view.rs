@@ -0,0 +1,357 @@
+use super::{error, Dimensions, Grid, Location, Offset, Result};
+use std::{fmt, ops::Index, ops::Range};
+
+/// Borrowed, read-only window onto a [`Grid`] that may be transposed,
+/// rotated, flipped, or restricted to a rectangular sub-grid.
+///
+/// Transforming a view never copies values: each view stores the grid
+/// coordinate of its top-left corner and the steps taken through the grid
+/// when moving one row down or one column right. Use [`GridView::to_grid`] to
+/// materialize the result.
+///
+/// # Examples
+///
+/// ```
+/// use advent::utils::grid::*;
+/// use std::str::FromStr;
+///
+/// let grid = Grid::from_str("abc\ndef").unwrap();
+/// assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+/// assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+/// assert_eq!(grid.flip_h().to_string(), "cba\nfed");
+///
+/// let corner = grid.subgrid(0..2, 1..3).unwrap();
+/// assert_eq!(corner.flip_v().to_string(), "ef\nbc");
+/// ```
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Offset,
+    row_step: Offset,
+    col_step: Offset,
+    dimensions: Dimensions,
+}
+
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<'a, T> GridView<'a, T> {
+    pub(super) fn new(grid: &'a Grid<T>) -> Self {
+        Self {
+            grid,
+            origin: (0, 0),
+            row_step: (1, 0),
+            col_step: (0, 1),
+            dimensions: *grid.dimensions(),
+        }
+    }
+
+    pub fn dimensions(&self) -> &Dimensions {
+        &self.dimensions
+    }
+
+    pub fn rows(&self) -> usize {
+        self.dimensions.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.dimensions.cols
+    }
+
+    /// Returns the location in the underlying [`Grid`] shown at `location`
+    /// of the view, or `None` if it lies outside the view.
+    pub fn source(&self, location: Location) -> Option<Location> {
+        let (r, c) = location.coordinate(&self.dimensions).ok()?;
+        Some(self.project(r as isize, c as isize))
+    }
+
+    /// Returns a reference to the value at `location`, or `None` if it lies
+    /// outside the view.
+    pub fn get(&self, location: Location) -> Option<&'a T> {
+        let grid = self.grid;
+        self.source(location).map(|source| &grid[source])
+    }
+
+    /// Iterates over the values in `row` from left to right, or returns
+    /// `None` if it lies outside the view.
+    pub fn row(&self, row: usize) -> Option<impl Iterator<Item = &'a T> + Clone + 'a> {
+        self.dimensions.check_row(row).ok()?;
+        let view = *self;
+        Some((0..self.cols()).map(move |col| view.at(row, col)))
+    }
+
+    /// Iterates over the values in `col` from top to bottom, or returns
+    /// `None` if it lies outside the view.
+    pub fn column(&self, col: usize) -> Option<impl Iterator<Item = &'a T> + Clone + 'a> {
+        self.dimensions.check_col(col).ok()?;
+        let view = *self;
+        Some((0..self.rows()).map(move |row| view.at(row, col)))
+    }
+
+    /// Iterates over each row, yielding an iterator over its values.
+    pub fn iter_rows(&self) -> impl Iterator<Item = impl Iterator<Item = &'a T> + Clone + 'a> + 'a {
+        let view = *self;
+        (0..self.rows()).flat_map(move |row| view.row(row))
+    }
+
+    /// Iterates over each column, yielding an iterator over its values.
+    pub fn iter_columns(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = &'a T> + Clone + 'a> + 'a {
+        let view = *self;
+        (0..self.cols()).flat_map(move |col| view.column(col))
+    }
+
+    /// Iterates over all values in row-major order of the view.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+        self.iter_rows().flatten()
+    }
+
+    /// Swaps rows and columns, mirroring along the main diagonal.
+    pub fn transpose(&self) -> Self {
+        Self {
+            row_step: self.col_step,
+            col_step: self.row_step,
+            dimensions: Dimensions::new(self.cols(), self.rows()),
+            ..*self
+        }
+    }
+
+    /// Rotates a quarter turn clockwise, so the first column becomes the
+    /// first row read from bottom to top.
+    pub fn rotate_cw(&self) -> Self {
+        Self {
+            origin: self.corner(self.rows() as isize - 1, 0),
+            row_step: self.col_step,
+            col_step: negate(self.row_step),
+            dimensions: Dimensions::new(self.cols(), self.rows()),
+            ..*self
+        }
+    }
+
+    /// Rotates a quarter turn counterclockwise, so the last column becomes
+    /// the first row.
+    pub fn rotate_ccw(&self) -> Self {
+        Self {
+            origin: self.corner(0, self.cols() as isize - 1),
+            row_step: negate(self.col_step),
+            col_step: self.row_step,
+            dimensions: Dimensions::new(self.cols(), self.rows()),
+            ..*self
+        }
+    }
+
+    /// Mirrors left to right, reversing every row.
+    pub fn flip_h(&self) -> Self {
+        Self {
+            origin: self.corner(0, self.cols() as isize - 1),
+            col_step: negate(self.col_step),
+            ..*self
+        }
+    }
+
+    /// Mirrors top to bottom, reversing every column.
+    pub fn flip_v(&self) -> Self {
+        Self {
+            origin: self.corner(self.rows() as isize - 1, 0),
+            row_step: negate(self.row_step),
+            ..*self
+        }
+    }
+
+    /// Restricts the view to the half-open ranges `rows` and `cols`.
+    /// Empty ranges are allowed, but both must end within the view.
+    pub fn subgrid(&self, rows: Range<usize>, cols: Range<usize>) -> Result<Self> {
+        Self::check_range(&rows, self.rows(), "row")?;
+        Self::check_range(&cols, self.cols(), "column")?;
+
+        Ok(Self {
+            origin: self.corner(rows.start as isize, cols.start as isize),
+            dimensions: Dimensions::new(rows.len(), cols.len()),
+            ..*self
+        })
+    }
+
+    /// Copies the values shown by the view into a new [`Grid`].
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            values: self.iter().cloned().collect(),
+            dimensions: self.dimensions,
+        }
+    }
+
+    fn check_range(range: &Range<usize>, len: usize, item: &'static str) -> Result<()> {
+        let (value, max_value) = if range.start > range.end {
+            (range.start, range.end + 1)
+        } else {
+            (range.end, len + 1)
+        };
+
+        (value < max_value)
+            .then_some(())
+            .ok_or(error::Error::IndexOutOfBounds {
+                item,
+                value,
+                max_value,
+            })
+    }
+
+    fn corner(&self, r: isize, c: isize) -> Offset {
+        (
+            self.origin.0 + r * self.row_step.0 + c * self.col_step.0,
+            self.origin.1 + r * self.row_step.1 + c * self.col_step.1,
+        )
+    }
+
+    fn project(&self, r: isize, c: isize) -> Location {
+        let (r, c) = self.corner(r, c);
+        Location::Coordinate(r as usize, c as usize)
+    }
+
+    fn at(&self, row: usize, col: usize) -> &'a T {
+        let grid = self.grid;
+        &grid[self.project(row as isize, col as isize)]
+    }
+}
+
+fn negate((r, c): Offset) -> Offset {
+    (-r, -c)
+}
+
+impl<T> Index<Location> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, location: Location) -> &Self::Output {
+        match location.coordinate(&self.dimensions) {
+            Ok((r, c)) => self.at(r, c),
+            Err(err) => panic!("{err}"),
+        }
+    }
+}
+
+/// Views are equal when they show the same values in the same shape,
+/// regardless of the grids or transforms behind them.
+impl<T: PartialEq> PartialEq for GridView<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dimensions == other.dimensions && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for GridView<'_, T> {}
+
+impl<T: fmt::Display> fmt::Display for GridView<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.iter_rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            for value in row {
+                write!(f, "{value}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn grid() -> Grid<char> {
+        Grid::from_str("abcd\nefgh\nijkl").unwrap()
+    }
+
+    #[test]
+    fn identity() {
+        let grid = grid();
+        let view = grid.view();
+
+        assert_eq!(view.to_grid(), grid);
+        assert_eq!(view.to_string(), grid.to_string());
+        assert_eq!(view[Location::Index(5)], 'f');
+        assert_eq!(view.get(Location::Coordinate(3, 0)), None);
+    }
+
+    #[test]
+    fn transforms_on_non_square_grids() {
+        let grid = grid();
+
+        assert_eq!(grid.transpose().to_string(), "aei\nbfj\ncgk\ndhl");
+        assert_eq!(grid.rotate_cw().to_string(), "iea\njfb\nkgc\nlhd");
+        assert_eq!(grid.rotate_ccw().to_string(), "dhl\ncgk\nbfj\naei");
+        assert_eq!(grid.flip_h().to_string(), "dcba\nhgfe\nlkji");
+        assert_eq!(grid.flip_v().to_string(), "ijkl\nefgh\nabcd");
+        assert_eq!(grid.rotate_cw().dimensions(), &Dimensions::new(4, 3));
+    }
+
+    #[test]
+    fn transforms_compose() {
+        let grid = grid();
+        let view = grid.view();
+
+        assert_eq!(view.rotate_cw().rotate_ccw(), view);
+        assert_eq!(view.rotate_cw().rotate_cw(), view.flip_h().flip_v());
+        assert_eq!(view.transpose().transpose(), view);
+        assert_eq!(view.transpose().flip_h(), view.rotate_cw());
+        assert_eq!(view.rotate_cw().rotate_cw().rotate_cw(), view.rotate_ccw());
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = grid();
+        let view = grid.rotate_ccw();
+
+        assert_eq!(view.row(1).unwrap().collect::<String>(), "cgk");
+        assert_eq!(view.column(2).unwrap().collect::<String>(), "lkji");
+        assert!(view.row(4).is_none());
+        assert!(view.column(3).is_none());
+        assert_eq!(
+            view.iter_columns()
+                .map(|col| col.collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["dcba", "hgfe", "lkji"]
+        );
+        assert_eq!(
+            view.source(Location::Coordinate(0, 0)),
+            Some(Location::Coordinate(0, 3))
+        );
+    }
+
+    #[test]
+    fn subgrids() {
+        let grid = grid();
+
+        let middle = grid.subgrid(1..3, 1..4).unwrap();
+        assert_eq!(middle.to_string(), "fgh\njkl");
+        assert_eq!(middle.flip_h().to_string(), "hgf\nlkj");
+        assert_eq!(middle.rotate_cw().to_string(), "jf\nkg\nlh");
+        assert_eq!(middle.subgrid(1..2, 0..2).unwrap().to_string(), "jk");
+        assert_eq!(grid.flip_v().subgrid(0..1, 2..4).unwrap().to_string(), "kl");
+
+        let empty = grid.subgrid(3..3, 0..4).unwrap();
+        assert_eq!(empty.rows(), 0);
+        assert_eq!(empty.iter().count(), 0);
+        assert_eq!(empty.flip_v().to_string(), "");
+
+        assert_eq!(
+            grid.subgrid(0..4, 0..1),
+            Err(error::Error::IndexOutOfBounds {
+                item: "row",
+                value: 4,
+                max_value: 4
+            })
+        );
+        assert!(middle.subgrid(0..1, 2..4).is_err());
+    }
+}
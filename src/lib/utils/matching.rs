@@ -0,0 +1,280 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Candidate slots for each key of an assignment puzzle
+pub type Candidates<K, S> = HashMap<K, HashSet<S>>;
+
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum Error<K: Debug> {
+    /// No slot is left for the key once the other keys are assigned
+    #[error("no slot remains for {0:?}")]
+    Infeasible(K),
+    /// Propagation stalled with every remaining key allowing several slots
+    #[error("{} keys could not be narrowed to a single slot", .0.len())]
+    Ambiguous(Vec<K>),
+}
+
+/// Assigns every key a distinct slot by repeatedly fixing keys with a single
+/// remaining candidate and striking that slot from every other key.
+///
+/// # Examples
+///
+/// ```
+/// use advent::utils::matching::{self, Candidates};
+/// use std::collections::{HashMap, HashSet};
+///
+/// let candidates: Candidates<&str, u8> = HashMap::from([
+///     ("row", HashSet::from([0, 1, 2])),
+///     ("class", HashSet::from([1, 2])),
+///     ("seat", HashSet::from([2])),
+/// ]);
+///
+/// let assignment = matching::propagate(candidates).unwrap();
+/// assert_eq!(assignment, HashMap::from([("row", 0), ("class", 1), ("seat", 2)]));
+/// ```
+pub fn propagate<K, S>(mut candidates: Candidates<K, S>) -> Result<HashMap<K, S>, Error<K>>
+where
+    K: Clone + Debug + Eq + Hash,
+    S: Clone + Eq + Hash,
+{
+    let mut assignment = HashMap::new();
+
+    while !candidates.is_empty() {
+        if let Some((key, _)) = candidates.iter().find(|(_, slots)| slots.is_empty()) {
+            return Err(Error::Infeasible(key.clone()));
+        }
+
+        let singles: Vec<(K, S)> = candidates
+            .iter()
+            .filter(|(_, slots)| slots.len() == 1)
+            .filter_map(|(key, slots)| Some((key.clone(), slots.iter().next()?.clone())))
+            .collect();
+
+        if singles.is_empty() {
+            return Err(Error::Ambiguous(candidates.into_keys().collect()));
+        }
+
+        for (key, slot) in singles {
+            // An earlier single this round may have claimed the same slot
+            if !candidates[&key].contains(&slot) {
+                continue;
+            }
+
+            candidates.remove(&key);
+            for slots in candidates.values_mut() {
+                slots.remove(&slot);
+            }
+            assignment.insert(key, slot);
+        }
+    }
+
+    Ok(assignment)
+}
+
+/// Finds a largest set of key–slot pairs such that each key and each slot
+/// appears at most once, using the Hopcroft–Karp algorithm. When several
+/// maximum matchings exist, any one of them may be returned.
+pub fn maximum_matching<K, S>(candidates: &Candidates<K, S>) -> HashMap<K, S>
+where
+    K: Clone + Eq + Hash,
+    S: Clone + Eq + Hash,
+{
+    let keys: Vec<&K> = candidates.keys().collect();
+    let mut slots: Vec<&S> = Vec::new();
+    let mut slot_indices: HashMap<&S, usize> = HashMap::new();
+
+    let adjacency = keys
+        .iter()
+        .map(|key| {
+            candidates[*key]
+                .iter()
+                .map(|slot| {
+                    *slot_indices.entry(slot).or_insert_with(|| {
+                        slots.push(slot);
+                        slots.len() - 1
+                    })
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut matcher = HopcroftKarp::new(adjacency, slots.len());
+    matcher.run();
+
+    matcher
+        .key_partner
+        .iter()
+        .enumerate()
+        .filter_map(|(key, slot)| slot.map(|slot| (keys[key].clone(), slots[slot].clone())))
+        .collect()
+}
+
+/// Assigns every key a distinct slot with [`maximum_matching`], reporting a
+/// key left without a slot if no such assignment exists. Unlike
+/// [`propagate`], this does not check that the assignment is unique.
+pub fn perfect_matching<K, S>(candidates: &Candidates<K, S>) -> Result<HashMap<K, S>, Error<K>>
+where
+    K: Clone + Debug + Eq + Hash,
+    S: Clone + Eq + Hash,
+{
+    let matching = maximum_matching(candidates);
+
+    match candidates.keys().find(|key| !matching.contains_key(*key)) {
+        Some(key) => Err(Error::Infeasible(key.clone())),
+        None => Ok(matching),
+    }
+}
+
+/// Maximum bipartite matching between keys and slots numbered from zero
+struct HopcroftKarp {
+    adjacency: Vec<Vec<usize>>,
+    key_partner: Vec<Option<usize>>,
+    slot_partner: Vec<Option<usize>>,
+    /// Breadth-first layer of each key in the current phase
+    layer: Vec<usize>,
+}
+
+impl HopcroftKarp {
+    fn new(adjacency: Vec<Vec<usize>>, num_slots: usize) -> Self {
+        let num_keys = adjacency.len();
+        Self {
+            adjacency,
+            key_partner: vec![None; num_keys],
+            slot_partner: vec![None; num_slots],
+            layer: vec![usize::MAX; num_keys],
+        }
+    }
+
+    fn run(&mut self) {
+        while self.build_layers() {
+            for key in 0..self.adjacency.len() {
+                if self.key_partner[key].is_none() {
+                    self.augment(key);
+                }
+            }
+        }
+    }
+
+    /// Layers keys by their distance from an unmatched key along alternating
+    /// paths, returning `true` if an augmenting path exists.
+    fn build_layers(&mut self) -> bool {
+        let mut queue = VecDeque::new();
+        for key in 0..self.adjacency.len() {
+            self.layer[key] = match self.key_partner[key] {
+                Some(_) => usize::MAX,
+                None => {
+                    queue.push_back(key);
+                    0
+                }
+            };
+        }
+
+        let mut found = false;
+        while let Some(key) = queue.pop_front() {
+            for &slot in &self.adjacency[key] {
+                match self.slot_partner[slot] {
+                    None => found = true,
+                    Some(next) if self.layer[next] == usize::MAX => {
+                        self.layer[next] = self.layer[key] + 1;
+                        queue.push_back(next);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
+        found
+    }
+
+    /// Extends the matching along a shortest augmenting path from `key`.
+    fn augment(&mut self, key: usize) -> bool {
+        for i in 0..self.adjacency[key].len() {
+            let slot = self.adjacency[key][i];
+            let reachable = match self.slot_partner[slot] {
+                None => true,
+                Some(next) => self.layer[next] == self.layer[key] + 1 && self.augment(next),
+            };
+
+            if reachable {
+                self.key_partner[key] = Some(slot);
+                self.slot_partner[slot] = Some(key);
+                return true;
+            }
+        }
+
+        self.layer[key] = usize::MAX;
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates(pairs: &[(char, &[u8])]) -> Candidates<char, u8> {
+        pairs
+            .iter()
+            .map(|&(key, slots)| (key, slots.iter().copied().collect()))
+            .collect()
+    }
+
+    #[test]
+    fn propagation_beyond_chains() {
+        // Not a chain of nested sets: `b` and `c` only resolve after `a`
+        let assignment = propagate(candidates(&[
+            ('a', &[3]),
+            ('b', &[1, 3]),
+            ('c', &[2, 3]),
+            ('d', &[1, 2, 4]),
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            assignment,
+            HashMap::from([('a', 3), ('b', 1), ('c', 2), ('d', 4)])
+        );
+    }
+
+    #[test]
+    fn propagation_errors() {
+        assert!(matches!(
+            propagate(candidates(&[('a', &[1]), ('b', &[1])])),
+            Err(Error::Infeasible('a' | 'b'))
+        ));
+
+        let Err(Error::Ambiguous(mut keys)) =
+            propagate(candidates(&[('a', &[1, 2]), ('b', &[1, 2]), ('c', &[3])]))
+        else {
+            panic!("expected an ambiguous assignment");
+        };
+        keys.sort();
+        assert_eq!(keys, vec!['a', 'b']);
+    }
+
+    #[test]
+    fn maximum_matchings() {
+        // Greedily matching `a` to 1 would leave `b` without a slot
+        let pairs = candidates(&[('a', &[1, 2]), ('b', &[1]), ('c', &[2, 3])]);
+        let matching = perfect_matching(&pairs).unwrap();
+
+        assert_eq!(matching[&'b'], 1);
+        assert_eq!(matching[&'a'], 2);
+        assert_eq!(matching[&'c'], 3);
+
+        let pairs = candidates(&[('a', &[1]), ('b', &[1]), ('c', &[1, 2])]);
+        assert_eq!(maximum_matching(&pairs).len(), 2);
+        assert!(matches!(
+            perfect_matching(&pairs),
+            Err(Error::Infeasible('a' | 'b'))
+        ));
+    }
+
+    #[test]
+    fn ambiguous_but_feasible() {
+        let pairs = candidates(&[('a', &[1, 2]), ('b', &[1, 2])]);
+
+        assert!(propagate(pairs.clone()).is_err());
+        assert_eq!(perfect_matching(&pairs).unwrap().len(), 2);
+    }
+}
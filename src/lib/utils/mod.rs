@@ -1,9 +1,14 @@
 /// Tools for working with ascii values
 pub mod ascii;
+/// Tools for simulating cellular automata
+pub mod automaton;
 pub mod cli;
-pub mod error;
 pub mod graph;
 /// Tools for working with 2-dimensional grids
 pub mod grid;
+/// Tools for assigning keys to distinct slots
+pub mod matching;
+/// Tools for parsing puzzle input line by line
+pub mod parse;
 pub mod solution;
 pub mod verification;
@@ -0,0 +1,116 @@
+use crate::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Values parsed from the lines of an input, alongside an error for every
+/// line that was skipped because it could not be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParsedLines<T> {
+    /// Values from the lines that parsed, in input order
+    pub values: Vec<T>,
+    /// A [`Error::Parse`] for each line that did not, in input order
+    pub errors: Vec<Error>,
+}
+
+impl<T> ParsedLines<T> {
+    /// Whether every line parsed
+    pub fn is_clean(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns the values only if every line parsed, and otherwise the error
+    /// for the first line that did not
+    pub fn strict(self) -> Result<Vec<T>, Error> {
+        match self.errors.into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(self.values),
+        }
+    }
+}
+
+/// Parses each line of `input` with `parse`, skipping lines that fail and
+/// recording why, with their line numbers counted from one. Parsed values may
+/// borrow from `input`.
+///
+/// # Examples
+///
+/// ```
+/// use advent::utils::parse;
+/// use advent::Error;
+///
+/// let parsed = parse::lines("1\nx\n3", |line| line.parse::<u8>());
+/// assert_eq!(parsed.values, vec![1, 3]);
+/// assert_eq!(
+///     parsed.errors,
+///     vec![Error::at_line(2, "x", "invalid digit found in string")]
+/// );
+///
+/// assert!(parse::lines("1\n2", |line| line.parse::<u8>()).strict().is_ok());
+/// ```
+pub fn lines<'a, T, E, F>(input: &'a str, mut parse: F) -> ParsedLines<T>
+where
+    E: Display,
+    F: FnMut(&'a str) -> Result<T, E>,
+{
+    let mut parsed = ParsedLines {
+        values: Vec::new(),
+        errors: Vec::new(),
+    };
+
+    for (index, line) in input.lines().enumerate() {
+        match parse(line) {
+            Ok(value) => parsed.values.push(value),
+            Err(err) => parsed.errors.push(Error::at_line(index + 1, line, err)),
+        }
+    }
+
+    parsed
+}
+
+/// Parses a number, or any other [`FromStr`] value, from each line of `input`
+pub fn numbers<N>(input: &str) -> ParsedLines<N>
+where
+    N: FromStr,
+    N::Err: Display,
+{
+    lines(input, str::parse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collects_every_failure() {
+        let parsed = numbers::<i32>("10\n\n-4\n1.5\n7");
+
+        assert_eq!(parsed.values, vec![10, -4, 7]);
+        assert!(!parsed.is_clean());
+        assert_eq!(
+            parsed
+                .errors
+                .iter()
+                .map(|error| match error {
+                    Error::Parse { line, text, .. } => (*line, text.as_str()),
+                    _ => unreachable!(),
+                })
+                .collect::<Vec<_>>(),
+            vec![(2, ""), (4, "1.5")]
+        );
+        assert_eq!(
+            parsed.strict(),
+            Err(Error::at_line(
+                2,
+                "",
+                "cannot parse integer from empty string"
+            ))
+        );
+    }
+
+    #[test]
+    fn empty_input() {
+        let parsed = numbers::<u8>("");
+        assert!(parsed.is_clean());
+        assert_eq!(parsed.strict(), Ok(vec![]));
+    }
+}
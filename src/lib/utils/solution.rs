@@ -2,6 +2,18 @@ pub trait Solution {
     fn part_one(&self, input: &str) -> Option<String>;
     fn part_two(&self, input: &str) -> Option<String>;
 
+    /// Describes a graph built from `input` in the Graphviz DOT language, for
+    /// days whose puzzle involves one
+    fn dot(&self, _input: &str) -> Option<String> {
+        None
+    }
+
+    /// Problems found in `input` that the parts skip over, such as lines
+    /// that could not be parsed, for days that tolerate them
+    fn diagnose(&self, _input: &str) -> Vec<crate::Error> {
+        Vec::new()
+    }
+
     fn run(&self, input: &str) -> [Option<String>; 2] {
         [self.part_one(input), self.part_two(input)]
     }
@@ -5,6 +5,7 @@ fn main() {
     let root_dir = cli.get_input_dir();
 
     let all_solutions = solutions::all_solutions();
+    let mut failed = false;
 
     for year in cli.get_years() {
         if let Some(year_association) = all_solutions.get(&year) {
@@ -15,7 +16,18 @@ fn main() {
                         .join(format!("day{0:02}", day))
                         .with_extension("txt");
                     if let Ok(input) = std::fs::read_to_string(&fname) {
+                        let diagnostics = solution.diagnose(&input);
+                        for error in &diagnostics {
+                            eprintln!("{}", error.diagnostic(&fname));
+                        }
+                        if cli.is_strict() && !diagnostics.is_empty() {
+                            eprintln!("Day {:02} failed: input has malformed lines", day);
+                            failed = true;
+                            continue;
+                        }
+
                         solution.execute_with_timing(day, &input);
+                        write_dot(&cli, year, day, solution.as_ref(), &input);
                     } else {
                         eprintln!("File {} not found", fname.display());
                     }
@@ -23,4 +35,26 @@ fn main() {
             }
         }
     }
+
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+fn write_dot(
+    cli: &utils::cli::CommandLineInterface,
+    year: i32,
+    day: i32,
+    solution: &dyn utils::solution::Solution,
+    input: &str,
+) {
+    let Some(path) = cli.get_dot_file(year, day) else {
+        return;
+    };
+
+    if let Some(dot) = solution.dot(input) {
+        if let Err(err) = std::fs::write(&path, dot) {
+            eprintln!("Could not write {}: {}", path.display(), err);
+        }
+    }
 }